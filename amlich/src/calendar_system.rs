@@ -0,0 +1,134 @@
+use super::{
+    canchi::EarthlyBranch,
+    time_zone::{TimeZone, TimeZoneRule},
+};
+
+/// Lunisolar calendars computed with the same algorithm. They only differ by the
+/// meridian used to decide on which day a new moon or a solar term falls, so the
+/// dates diverge when one falls close to midnight, e.g. Tết 1985 and 2007.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum CalendarSystem {
+    /// UTC+7 at every date, as the published almanacs. Pass a
+    /// `TimeZone::Vietnam` rule to convert with the historical offsets instead.
+    #[default]
+    Vietnamese,
+    Chinese,
+    Korean,
+}
+
+pub const CALENDAR_SYSTEMS: [CalendarSystem; 3] = [
+    CalendarSystem::Vietnamese,
+    CalendarSystem::Chinese,
+    CalendarSystem::Korean,
+];
+
+impl CalendarSystem {
+    pub fn time_zone(&self) -> TimeZone {
+        match self {
+            CalendarSystem::Vietnamese => TimeZone::Fixed(7),
+            CalendarSystem::Chinese => TimeZone::Fixed(8),
+            CalendarSystem::Korean => TimeZone::Korea,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CalendarSystem::Vietnamese => "Vietnamese",
+            CalendarSystem::Chinese => "Chinese",
+            CalendarSystem::Korean => "Korean",
+        }
+    }
+
+    /// Zodiac animal of the branch, e.g. Mão is the cat in Vietnam and the rabbit elsewhere
+    pub fn zodiac_animal(&self, branch: EarthlyBranch) -> &'static str {
+        match (self, branch) {
+            (_, EarthlyBranch::Ty) => "Rat",
+            (CalendarSystem::Vietnamese, EarthlyBranch::Suu) => "Buffalo",
+            (_, EarthlyBranch::Suu) => "Ox",
+            (_, EarthlyBranch::Dan) => "Tiger",
+            (CalendarSystem::Vietnamese, EarthlyBranch::Mao) => "Cat",
+            (_, EarthlyBranch::Mao) => "Rabbit",
+            (_, EarthlyBranch::Thin) => "Dragon",
+            (_, EarthlyBranch::Ti) => "Snake",
+            (_, EarthlyBranch::Ngo) => "Horse",
+            (CalendarSystem::Korean, EarthlyBranch::Mui) => "Sheep",
+            (_, EarthlyBranch::Mui) => "Goat",
+            (_, EarthlyBranch::Than) => "Monkey",
+            (_, EarthlyBranch::Dau) => "Rooster",
+            (_, EarthlyBranch::Tuat) => "Dog",
+            (_, EarthlyBranch::Hoi) => "Pig",
+        }
+    }
+}
+
+impl TimeZoneRule for CalendarSystem {
+    fn offset_minutes(&self, jd: i64) -> i64 {
+        self.time_zone().offset_minutes(jd)
+    }
+
+    fn fixed_offset_minutes(&self) -> Option<i64> {
+        self.time_zone().fixed_offset_minutes()
+    }
+}
+
+impl From<CalendarSystem> for TimeZone {
+    fn from(calendar: CalendarSystem) -> Self {
+        calendar.time_zone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lunar2solar, solar2lunar, LunarDate, SolarDate};
+
+    #[test]
+    fn tet_by_calendar_test() {
+        let tet = |year, calendar| lunar2solar(LunarDate::new(year, 1, 1, false), calendar);
+        assert_eq!(
+            "1985-01-21",
+            tet(1985, CalendarSystem::Vietnamese).to_string()
+        );
+        assert_eq!("1985-02-20", tet(1985, CalendarSystem::Chinese).to_string());
+        assert_eq!(
+            "2007-02-17",
+            tet(2007, CalendarSystem::Vietnamese).to_string()
+        );
+        assert_eq!("2007-02-18", tet(2007, CalendarSystem::Chinese).to_string());
+        assert_eq!("2007-02-18", tet(2007, CalendarSystem::Korean).to_string());
+        assert_eq!("2024-02-10", tet(2024, CalendarSystem::Korean).to_string());
+    }
+
+    #[test]
+    fn solar2lunar_by_calendar_test() {
+        let date = SolarDate::new(1985, 1, 21);
+        let result = solar2lunar(date, CalendarSystem::Vietnamese);
+        assert_eq!("1985-01-01", result.to_string());
+        let result = solar2lunar(date, CalendarSystem::Chinese);
+        assert_eq!("1984-12-01", result.to_string());
+        assert!(!result.is_leap);
+    }
+
+    #[test]
+    fn vietnamese_fixed_offset_test() {
+        assert_eq!(TimeZone::Fixed(7), CalendarSystem::Vietnamese.time_zone());
+        assert_eq!(Some(420), CalendarSystem::Vietnamese.fixed_offset_minutes());
+        // UTC+8 in Hà Nội in 1950 only changes the date with the historical rule
+        let date = SolarDate::new(1950, 8, 13);
+        let result = solar2lunar(date, CalendarSystem::Vietnamese);
+        assert_eq!("1950-07-01", result.to_string());
+        let result = solar2lunar(date, TimeZone::Vietnam(crate::VietnamRegion::North));
+        assert_eq!("1950-06-30", result.to_string());
+    }
+
+    #[test]
+    fn zodiac_animal_test() {
+        let mao = EarthlyBranch::Mao;
+        assert_eq!("Cat", CalendarSystem::Vietnamese.zodiac_animal(mao));
+        assert_eq!("Rabbit", CalendarSystem::Chinese.zodiac_animal(mao));
+        assert_eq!("Rabbit", CalendarSystem::Korean.zodiac_animal(mao));
+        let mui = EarthlyBranch::Mui;
+        assert_eq!("Goat", CalendarSystem::Chinese.zodiac_animal(mui));
+        assert_eq!("Sheep", CalendarSystem::Korean.zodiac_animal(mui));
+    }
+}
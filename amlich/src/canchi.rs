@@ -0,0 +1,307 @@
+use std::fmt;
+
+use super::{fns::jd_from_date, julian_day::JulianDay, LunarDate, SolarDate};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HeavenlyStem {
+    Giap = 0,
+    At = 1,
+    Binh = 2,
+    Dinh = 3,
+    Mau = 4,
+    Ky = 5,
+    Canh = 6,
+    Tan = 7,
+    Nham = 8,
+    Quy = 9,
+}
+
+pub const HEAVENLY_STEMS: [HeavenlyStem; 10] = [
+    HeavenlyStem::Giap,
+    HeavenlyStem::At,
+    HeavenlyStem::Binh,
+    HeavenlyStem::Dinh,
+    HeavenlyStem::Mau,
+    HeavenlyStem::Ky,
+    HeavenlyStem::Canh,
+    HeavenlyStem::Tan,
+    HeavenlyStem::Nham,
+    HeavenlyStem::Quy,
+];
+
+impl HeavenlyStem {
+    /// Stem at the given position of the cycle, wraps around for any value
+    pub fn from_index(index: i64) -> Self {
+        HEAVENLY_STEMS[index.rem_euclid(10) as usize]
+    }
+
+    #[inline]
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            HeavenlyStem::Giap => "Giáp",
+            HeavenlyStem::At => "Ất",
+            HeavenlyStem::Binh => "Bính",
+            HeavenlyStem::Dinh => "Đinh",
+            HeavenlyStem::Mau => "Mậu",
+            HeavenlyStem::Ky => "Kỷ",
+            HeavenlyStem::Canh => "Canh",
+            HeavenlyStem::Tan => "Tân",
+            HeavenlyStem::Nham => "Nhâm",
+            HeavenlyStem::Quy => "Quý",
+        }
+    }
+
+    /// Hán character of the stem, e.g. 甲 for Giáp
+    pub fn han(&self) -> char {
+        ['甲', '乙', '丙', '丁', '戊', '己', '庚', '辛', '壬', '癸'][self.index()]
+    }
+}
+
+impl fmt::Display for HeavenlyStem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EarthlyBranch {
+    /// Tý
+    Ty = 0,
+    /// Sửu
+    Suu = 1,
+    /// Dần
+    Dan = 2,
+    /// Mão
+    Mao = 3,
+    /// Thìn
+    Thin = 4,
+    /// Tỵ
+    Ti = 5,
+    /// Ngọ
+    Ngo = 6,
+    /// Mùi
+    Mui = 7,
+    /// Thân
+    Than = 8,
+    /// Dậu
+    Dau = 9,
+    /// Tuất
+    Tuat = 10,
+    /// Hợi
+    Hoi = 11,
+}
+
+pub const EARTHLY_BRANCHES: [EarthlyBranch; 12] = [
+    EarthlyBranch::Ty,
+    EarthlyBranch::Suu,
+    EarthlyBranch::Dan,
+    EarthlyBranch::Mao,
+    EarthlyBranch::Thin,
+    EarthlyBranch::Ti,
+    EarthlyBranch::Ngo,
+    EarthlyBranch::Mui,
+    EarthlyBranch::Than,
+    EarthlyBranch::Dau,
+    EarthlyBranch::Tuat,
+    EarthlyBranch::Hoi,
+];
+
+impl EarthlyBranch {
+    /// Branch at the given position of the cycle, wraps around for any value
+    pub fn from_index(index: i64) -> Self {
+        EARTHLY_BRANCHES[index.rem_euclid(12) as usize]
+    }
+
+    #[inline]
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            EarthlyBranch::Ty => "Tý",
+            EarthlyBranch::Suu => "Sửu",
+            EarthlyBranch::Dan => "Dần",
+            EarthlyBranch::Mao => "Mão",
+            EarthlyBranch::Thin => "Thìn",
+            EarthlyBranch::Ti => "Tỵ",
+            EarthlyBranch::Ngo => "Ngọ",
+            EarthlyBranch::Mui => "Mùi",
+            EarthlyBranch::Than => "Thân",
+            EarthlyBranch::Dau => "Dậu",
+            EarthlyBranch::Tuat => "Tuất",
+            EarthlyBranch::Hoi => "Hợi",
+        }
+    }
+
+    /// Hán character of the branch, e.g. 子 for Tý
+    pub fn han(&self) -> char {
+        ['子', '丑', '寅', '卯', '辰', '巳', '午', '未', '申', '酉', '戌', '亥'][self.index()]
+    }
+
+    /// Branch of the two-hour block containing the given hour (0-23), 23h-1h is Tý
+    pub fn from_hour(hour: u32) -> Self {
+        Self::from_index(hour.div_ceil(2) as i64)
+    }
+}
+
+impl fmt::Display for EarthlyBranch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A stem-branch pair of the sexagenary cycle, e.g. Giáp Thìn
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CanChi {
+    pub stem: HeavenlyStem,
+    pub branch: EarthlyBranch,
+}
+
+impl CanChi {
+    pub fn new(stem: HeavenlyStem, branch: EarthlyBranch) -> Self {
+        Self { stem, branch }
+    }
+
+    /// Hán characters of the pair, e.g. 甲辰 for Giáp Thìn
+    pub fn han(&self) -> String {
+        format!("{}{}", self.stem.han(), self.branch.han())
+    }
+
+    /// Position in the 60-pair cycle, Giáp Tý is 0 and Quý Hợi is 59
+    pub fn index(&self) -> usize {
+        (6 * self.stem.index() + 55 * self.branch.index()) % 60
+    }
+}
+
+impl fmt::Display for CanChi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.stem, self.branch)
+    }
+}
+
+pub fn year_can_chi(lunar_year: i32) -> CanChi {
+    let year = lunar_year as i64;
+    CanChi::new(
+        HeavenlyStem::from_index(year + 6),
+        EarthlyBranch::from_index(year + 8),
+    )
+}
+
+/// Month 1 is always a Dần month. A leap month has no name of its own
+/// and takes the Can Chi of the month it repeats.
+pub fn month_can_chi(lunar_year: i32, lunar_month: u32) -> CanChi {
+    let year = lunar_year as i64;
+    let month = lunar_month as i64;
+    CanChi::new(
+        HeavenlyStem::from_index(year * 12 + month + 3),
+        EarthlyBranch::from_index(month + 1),
+    )
+}
+
+pub fn day_can_chi(solar_date: SolarDate) -> CanChi {
+    JulianDay::from(solar_date).can_chi()
+}
+
+/// Can Chi of the two-hour block containing the hour (0-23) of the given day.
+/// The block from 23h belongs to the next day, as in printed almanacs.
+pub fn hour_can_chi(solar_date: SolarDate, hour: u32) -> CanChi {
+    let mut jd = jd_from_date(
+        solar_date.day as i64,
+        solar_date.month as i64,
+        solar_date.year as i64,
+    );
+    if hour >= 23 {
+        jd += 1;
+    }
+    let branch = EarthlyBranch::from_hour(hour);
+    // Stem of the Tý hour at the start of the day
+    let ty_stem = (jd + 9) * 2;
+    CanChi::new(
+        HeavenlyStem::from_index(ty_stem + branch.index() as i64),
+        branch,
+    )
+}
+
+impl LunarDate {
+    #[inline]
+    pub fn year_can_chi(&self) -> CanChi {
+        year_can_chi(self.year)
+    }
+
+    #[inline]
+    pub fn month_can_chi(&self) -> CanChi {
+        month_can_chi(self.year, self.month)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn year_can_chi_test() {
+        assert_eq!("Giáp Thìn", year_can_chi(2024).to_string());
+        assert_eq!("Giáp Tý", year_can_chi(1984).to_string());
+        assert_eq!("Mậu Thân", year_can_chi(1968).to_string());
+        assert_eq!("Ất Tỵ", year_can_chi(2025).to_string());
+    }
+
+    #[test]
+    fn month_can_chi_test() {
+        assert_eq!("Bính Dần", month_can_chi(2024, 1).to_string());
+        assert_eq!("Đinh Sửu", month_can_chi(2024, 12).to_string());
+        assert_eq!("Giáp Tý", month_can_chi(2023, 11).to_string());
+        // Leap month 6 of 2025 repeats the Can Chi of month 6
+        let leap = LunarDate::new(2025, 6, 1, true);
+        assert_eq!(month_can_chi(2025, 6), leap.month_can_chi());
+        assert_eq!("Quý Mùi", leap.month_can_chi().to_string());
+    }
+
+    #[test]
+    fn day_can_chi_test() {
+        // Tet Giap Thin
+        assert_eq!(
+            "Giáp Thìn",
+            day_can_chi(SolarDate::new(2024, 2, 10)).to_string()
+        );
+        assert_eq!(
+            "Ất Tỵ",
+            day_can_chi(SolarDate::new(2024, 2, 11)).to_string()
+        );
+        assert_eq!(
+            "Mậu Tuất",
+            day_can_chi(SolarDate::new(2025, 1, 29)).to_string()
+        );
+    }
+
+    #[test]
+    fn hour_can_chi_test() {
+        let date = SolarDate::new(2024, 2, 10);
+        assert_eq!("Giáp Tý", hour_can_chi(date, 0).to_string());
+        assert_eq!("Ất Sửu", hour_can_chi(date, 1).to_string());
+        assert_eq!("Tân Mùi", hour_can_chi(date, 13).to_string());
+        assert_eq!("Ất Hợi", hour_can_chi(date, 22).to_string());
+        // 23h is the Tý hour of the next day (Ất Tỵ day)
+        assert_eq!("Bính Tý", hour_can_chi(date, 23).to_string());
+    }
+
+    #[test]
+    fn can_chi_index_test() {
+        assert_eq!(0, year_can_chi(1984).index());
+        assert_eq!(40, year_can_chi(2024).index());
+        assert_eq!(59, year_can_chi(2043).index());
+    }
+
+    #[test]
+    fn han_test() {
+        assert_eq!("甲辰", year_can_chi(2024).han());
+        assert_eq!("甲子", year_can_chi(1984).han());
+        assert_eq!("癸亥", year_can_chi(2043).han());
+        assert_eq!('巳', EarthlyBranch::Ti.han());
+    }
+}
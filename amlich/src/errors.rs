@@ -0,0 +1,81 @@
+use std::error::Error;
+use std::fmt;
+
+/// Lowest lunar year `try_lunar2solar` accepts
+pub const MIN_YEAR: i32 = 1200;
+/// Highest lunar year `try_lunar2solar` accepts
+pub const MAX_YEAR: i32 = 2199;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LunarDateError {
+    /// The leap flag was set but the given month is not the leap month of that year
+    NoSuchLeapMonth { year: i32, month: u32 },
+    /// The day does not exist in the given lunar month (29 or 30 days)
+    DayOutOfRange { day: u32, days_in_month: u32 },
+    /// The month is not between 1 and 12
+    MonthOutOfRange(u32),
+    /// The year is outside MIN_YEAR..=MAX_YEAR
+    YearOutOfRange(i32),
+}
+
+impl fmt::Display for LunarDateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LunarDateError::NoSuchLeapMonth { year, month } => {
+                write!(f, "Month {month} is not a leap month in lunar year {year}")
+            }
+            LunarDateError::DayOutOfRange { day, days_in_month } => {
+                write!(
+                    f,
+                    "Day {day} is out of range, the lunar month has {days_in_month} days"
+                )
+            }
+            LunarDateError::MonthOutOfRange(month) => {
+                write!(f, "Month {month} is out of range, must be between 1-12")
+            }
+            LunarDateError::YearOutOfRange(year) => {
+                write!(
+                    f,
+                    "Year {year} is out of range, must be between {MIN_YEAR}-{MAX_YEAR}"
+                )
+            }
+        }
+    }
+}
+
+impl Error for LunarDateError {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParseDateError {
+    /// The text is not a date in one of the supported forms
+    InvalidFormat,
+    /// The solar date does not exist, e.g. 2023-02-29
+    NoSuchDate,
+    /// The lunar date does not exist in the calendar
+    Lunar(LunarDateError),
+}
+
+impl fmt::Display for ParseDateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseDateError::InvalidFormat => write!(f, "Invalid date format"),
+            ParseDateError::NoSuchDate => write!(f, "The date does not exist"),
+            ParseDateError::Lunar(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl Error for ParseDateError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseDateError::Lunar(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<LunarDateError> for ParseDateError {
+    fn from(error: LunarDateError) -> Self {
+        ParseDateError::Lunar(error)
+    }
+}
@@ -2,28 +2,46 @@
 
 use std::f32::consts::PI;
 
-use super::SolarDate;
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate};
+
+#[cfg(feature = "precise")]
+use super::precise;
+use super::{julian_day::Cutover, time_zone::TimeZoneRule, SolarDate};
 
 pub fn jd_from_date(dd: i64, mm: i64, yyyy: i64) -> i64 {
+    jd_from_date_with_cutover(dd, mm, yyyy, Cutover::default())
+}
+
+pub fn jd_from_date_with_cutover(dd: i64, mm: i64, yyyy: i64, cutover: Cutover) -> i64 {
     let a: i64 = ((14 - mm) / 12) as i64;
     let y = yyyy + 4800 - a;
     let m = mm + 12 * a - 3;
     let mut jd = dd + ((153 * m + 2) / 5) as i64 + 365 * y + (y / 4) as i64 - (y / 100) as i64
         + (y / 400) as i64
         - 32045;
-    if jd < 2299161 {
-        jd = dd + ((153 * m + 2) / 5) as i64 + 365 * y + (y / 4) as i64 - 32083;
+    if let Cutover::At(first_gregorian_day) = cutover {
+        if jd < first_gregorian_day {
+            jd = dd + ((153 * m + 2) / 5) as i64 + 365 * y + (y / 4) as i64 - 32083;
+        }
     }
     return jd;
 }
 
 pub fn jd_to_date(jd: i64) -> SolarDate {
+    jd_to_date_with_cutover(jd, Cutover::default())
+}
+
+pub fn jd_to_date_with_cutover(jd: i64, cutover: Cutover) -> SolarDate {
+    let is_gregorian = match cutover {
+        Cutover::At(first_gregorian_day) => jd >= first_gregorian_day,
+        Cutover::ProlepticGregorian => true,
+    };
     let a: i64;
     let b: i64;
     let c: i64;
 
-    if jd > 2299160 {
-        // After 5/10/1582, Gregorian calendar
+    if is_gregorian {
+        // After 5/10/1582 by default, Gregorian calendar
         a = jd + 32044;
         b = ((4 * a + 3) / 146097) as i64;
         c = a - ((b * 146097) / 4) as i64;
@@ -42,7 +60,38 @@ pub fn jd_to_date(jd: i64) -> SolarDate {
     return SolarDate::new(year as i32, month as u32, day as u32);
 }
 
-pub fn new_moon(ka: i64) -> f64 {
+// Julian day number of 0001-01-01 in the proleptic Gregorian calendar, minus one
+const JD_CE_OFFSET: i64 = 1721425;
+
+/// Convert an instant given as julian date (UT) to local date time in the given time zone
+pub fn jd_to_datetime(jd: f64, time_zone: &dyn TimeZoneRule) -> DateTime<FixedOffset> {
+    let offset_minutes = time_zone.offset_minutes(jd as i64);
+    let local = jd + 0.5 + offset_minutes as f64 / 1440.0;
+    let day_number = local.floor();
+    let seconds = ((local - day_number) * 86400.0).round() as i64;
+    let date = NaiveDate::from_num_days_from_ce_opt((day_number as i64 - JD_CE_OFFSET) as i32)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+        + Duration::seconds(seconds);
+    let offset = FixedOffset::east_opt((offset_minutes * 60) as i32).unwrap();
+
+    return date.and_local_timezone(offset).unwrap();
+}
+
+/// Julian date (UT) of the k-th new moon counted from 1900 January 1
+#[cfg(feature = "precise")]
+pub fn new_moon(k: i64) -> f64 {
+    precise::new_moon(k)
+}
+
+/// Julian date (UT) of the k-th new moon counted from 1900 January 1
+#[cfg(not(feature = "precise"))]
+pub fn new_moon(k: i64) -> f64 {
+    truncated_new_moon(k)
+}
+
+pub fn truncated_new_moon(ka: i64) -> f64 {
     let k = ka as f64;
     let T = k / 1236.85; // Time in Julian centuries from 1900 January 0.5
     let T2 = T * T;
@@ -60,23 +109,51 @@ pub fn new_moon(ka: i64) -> f64 {
     C1 = C1 - 0.0074 * (dr * (M - m_pr)).sin() + 0.0004 * (dr * (2.0 * F + M)).sin();
     C1 = C1 - 0.0004 * (dr * (2.0 * F - M)).sin() - 0.0006 * (dr * (2.0 * F + m_pr)).sin();
     C1 = C1 + 0.0010 * (dr * (2.0 * F - m_pr)).sin() + 0.0005 * (dr * (2.0 * m_pr + M)).sin();
-    let deltat: f64;
+
+    return Jd1 + C1 - delta_t(T);
+}
+
+/// Difference between dynamical time and universal time in days at the given julian date
+pub fn delta_t_days(jd: f64) -> f64 {
+    #[cfg(feature = "precise")]
+    return precise::delta_t(jd);
+    #[cfg(not(feature = "precise"))]
+    return delta_t((jd - 2415020.0) / 36525.0);
+}
+
+/// Difference between dynamical time and universal time in days,
+/// T in Julian centuries from 1900 January 0.5
+pub fn delta_t(T: f64) -> f64 {
+    let T2 = T * T;
+    let T3 = T2 * T;
     if T < -11.0 {
-        deltat = 0.001 + 0.000839 * T + 0.0002261 * T2 - 0.00000845 * T3 - 0.000000081 * T * T3;
+        0.001 + 0.000839 * T + 0.0002261 * T2 - 0.00000845 * T3 - 0.000000081 * T * T3
     } else {
-        deltat = -0.000278 + 0.000265 * T + 0.000262 * T2;
-    };
+        -0.000278 + 0.000265 * T + 0.000262 * T2
+    }
+}
 
-    return Jd1 + C1 - deltat;
+/// Number of the last new moon before the given julian day number, counted from the
+/// new moon of 1900-01-01. Rounded down so days before 1900 get negative numbers,
+/// truncating towards zero put them one lunation late.
+pub fn new_moon_index(jd: i64) -> i64 {
+    ((jd as f64 - 2415021.076998695) / 29.530588853).floor() as i64
 }
 
-pub fn get_new_moon_day(k: i64, time_zone: i64) -> i64 {
-    return (new_moon(k) + 0.5 + (time_zone as f64 / 24.0)) as i64;
+/// Number of the new moon nearest to the given julian day number, counted as in
+/// `new_moon_index`
+pub fn nearest_new_moon_index(jd: i64) -> i64 {
+    (0.5 + (jd as f64 - 2415021.076998695) / 29.530588853).floor() as i64
 }
 
-pub fn get_lunar_month11(yyyy: i64, time_zone: i64) -> i64 {
+pub fn get_new_moon_day(k: i64, time_zone: &dyn TimeZoneRule) -> i64 {
+    let nm = new_moon(k);
+    return (nm + 0.5 + (time_zone.offset_minutes(nm as i64) as f64 / 1440.0)) as i64;
+}
+
+pub fn get_lunar_month11(yyyy: i64, time_zone: &dyn TimeZoneRule) -> i64 {
     let off = jd_from_date(31, 12, yyyy) - 2415021;
-    let k = (off as f64 / 29.530588853) as i64;
+    let k = (off as f64 / 29.530588853).floor() as i64;
     let mut nm = get_new_moon_day(k, time_zone);
     let sun_long = get_sun_longitude(nm, time_zone); // sun longitude at local midnight
     if sun_long >= 9 {
@@ -85,7 +162,19 @@ pub fn get_lunar_month11(yyyy: i64, time_zone: i64) -> i64 {
     return nm;
 }
 
+/// Longitude of the sun in radians at the given julian date (UT)
+#[cfg(feature = "precise")]
+pub fn sun_longitude(jdn: f64) -> f64 {
+    precise::sun_longitude(jdn)
+}
+
+/// Longitude of the sun in radians at the given julian date (UT)
+#[cfg(not(feature = "precise"))]
 pub fn sun_longitude(jdn: f64) -> f64 {
+    truncated_sun_longitude(jdn)
+}
+
+pub fn truncated_sun_longitude(jdn: f64) -> f64 {
     let T = (jdn - 2451545.0) / 36525.0; // Time in Julian centuries from 2000-01-01 12:00:00 GMT
     let T2 = T * T;
     let dr = PI as f64 / 180.0; // degree to radian
@@ -95,17 +184,20 @@ pub fn sun_longitude(jdn: f64) -> f64 {
     DL = DL + (0.019993 - 0.000101 * T) * (dr * 2.0 * M).sin() + 0.000290 * (dr * 3.0 * M).sin();
     let mut L = L0 + DL; // true longitude, degree
     L = L * dr;
-    L = L - (PI as f64) * 2.0 * (((L / (PI as f64 * 2.0)) as i64) as f64); // Normalize to (0, 2*PI)
+    // Normalize to (0, 2*PI). Rounded down, before 2000 L is negative and truncating
+    // left it in (-2*PI, 0)
+    L = L - (PI as f64) * 2.0 * (L / (PI as f64 * 2.0)).floor();
 
     return L;
 }
 
-pub fn get_sun_longitude(jd: i64, time_zone: i64) -> i64 {
-    return (sun_longitude(jd as f64 - 0.5 - (time_zone as f64 / 24.0)) / PI as f64 * 6.0) as i64;
+pub fn get_sun_longitude(jd: i64, time_zone: &dyn TimeZoneRule) -> i64 {
+    let offset = time_zone.offset_minutes(jd) as f64 / 1440.0;
+    return (sun_longitude(jd as f64 - 0.5 - offset) / PI as f64 * 6.0) as i64;
 }
 
-pub fn get_leap_month_offset(a11: i64, time_zone: i64) -> i64 {
-    let k = ((a11 as f64 - 2415021.076998695) / 29.530588853 + 0.5) as i64;
+pub fn get_leap_month_offset(a11: i64, time_zone: &dyn TimeZoneRule) -> i64 {
+    let k = nearest_new_moon_index(a11);
     let mut last: i64;
     let mut i = 1; // We start with the month following lunar month 11
     let mut arc = get_sun_longitude(get_new_moon_day(k + i, time_zone), time_zone);
@@ -136,4 +228,23 @@ mod tests {
         assert_eq!(solar_date.month, 2);
         assert_eq!(solar_date.year, 2024);
     }
+
+    #[test]
+    fn new_moon_index_before_1900_test() {
+        assert_eq!(0, new_moon_index(jd_from_date(15, 1, 1900)));
+        assert_eq!(-1, new_moon_index(jd_from_date(15, 12, 1899)));
+        assert_eq!(-2, new_moon_index(jd_from_date(15, 11, 1899)));
+        assert_eq!(-1, nearest_new_moon_index(jd_from_date(5, 12, 1899)));
+    }
+
+    #[test]
+    fn sun_longitude_before_2000_test() {
+        // Winter solstice 1984 was on 21 Dec at 16:23 UTC
+        let jd = jd_from_date(21, 12, 1984) as f64 - 0.5;
+        let before = sun_longitude(jd + 16.0 / 24.0).to_degrees();
+        let after = sun_longitude(jd + 17.0 / 24.0).to_degrees();
+        assert!(before > 269.0 && before < 270.0);
+        assert!(after > 270.0 && after < 271.0);
+        assert_eq!(9, get_sun_longitude(jd_from_date(22, 12, 1984), &7));
+    }
 }
@@ -0,0 +1,273 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Sub, SubAssign},
+};
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use super::{
+    canchi::{CanChi, EarthlyBranch, HeavenlyStem},
+    errors::LunarDateError,
+    fns::{jd_from_date_with_cutover, jd_to_date_with_cutover},
+    solar2lunar,
+    time_zone::TimeZoneRule,
+    try_lunar2solar, LunarDate, SolarDate,
+};
+
+/// Julian day number of 15 October 1582, the first day of the Gregorian calendar
+pub const GREGORIAN_REFORM: i64 = 2299161;
+
+// Julian day number of the proleptic Gregorian 0001-01-01 minus one
+const JULIAN_DAY_OF_CE: i64 = 1721425;
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+/// Where solar dates switch from the Julian to the Gregorian calendar
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Cutover {
+    /// Julian calendar before the given julian day number, Gregorian from it
+    At(i64),
+    /// Gregorian calendar for every date, as `chrono` does
+    ProlepticGregorian,
+}
+
+impl Default for Cutover {
+    fn default() -> Self {
+        Cutover::At(GREGORIAN_REFORM)
+    }
+}
+
+/// Number of days since 1 January 4713 BC at noon, the day count every
+/// conversion of this crate goes through
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JulianDay(pub i64);
+
+impl JulianDay {
+    #[inline]
+    pub fn new(day_number: i64) -> Self {
+        JulianDay(day_number)
+    }
+
+    #[inline]
+    pub fn value(&self) -> i64 {
+        self.0
+    }
+
+    /// Julian day of the solar date, Julian calendar before 15 October 1582
+    pub fn from_solar_date(solar_date: SolarDate) -> Self {
+        Self::from_solar_date_with_cutover(solar_date, Cutover::default())
+    }
+
+    pub fn from_solar_date_with_cutover(solar_date: SolarDate, cutover: Cutover) -> Self {
+        JulianDay(jd_from_date_with_cutover(
+            solar_date.day as i64,
+            solar_date.month as i64,
+            solar_date.year as i64,
+            cutover,
+        ))
+    }
+
+    pub fn to_solar_date(&self) -> SolarDate {
+        self.to_solar_date_with_cutover(Cutover::default())
+    }
+
+    pub fn to_solar_date_with_cutover(&self, cutover: Cutover) -> SolarDate {
+        jd_to_date_with_cutover(self.0, cutover)
+    }
+
+    pub fn from_lunar_date(
+        lunar_date: LunarDate,
+        time_zone: impl TimeZoneRule,
+    ) -> Result<Self, LunarDateError> {
+        try_lunar2solar(lunar_date, time_zone).map(Self::from_solar_date)
+    }
+
+    pub fn to_lunar_date(&self, time_zone: impl TimeZoneRule) -> LunarDate {
+        solar2lunar(self.to_solar_date(), time_zone)
+    }
+
+    /// `chrono` dates are proleptic Gregorian, so this does not depend on a cutover
+    pub fn from_naive_date(date: NaiveDate) -> Self {
+        JulianDay(date.num_days_from_ce() as i64 + JULIAN_DAY_OF_CE)
+    }
+
+    /// None if the day is out of the range of `NaiveDate`
+    pub fn to_naive_date(&self) -> Option<NaiveDate> {
+        let days = i32::try_from(self.0 - JULIAN_DAY_OF_CE).ok()?;
+        NaiveDate::from_num_days_from_ce_opt(days)
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        // day 0 was a Monday
+        WEEKDAYS[self.0.rem_euclid(7) as usize]
+    }
+
+    /// Can Chi of the day, the 60-day cycle runs on without breaks
+    pub fn can_chi(&self) -> CanChi {
+        CanChi::new(
+            HeavenlyStem::from_index(self.0 + 9),
+            EarthlyBranch::from_index(self.0 + 1),
+        )
+    }
+
+    /// Number of days from `other` to this day, negative if `other` is later
+    #[inline]
+    pub fn days_since(&self, other: JulianDay) -> i64 {
+        self.0 - other.0
+    }
+}
+
+impl fmt::Display for JulianDay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<SolarDate> for JulianDay {
+    fn from(solar_date: SolarDate) -> Self {
+        Self::from_solar_date(solar_date)
+    }
+}
+
+impl From<JulianDay> for SolarDate {
+    fn from(julian_day: JulianDay) -> Self {
+        julian_day.to_solar_date()
+    }
+}
+
+impl From<NaiveDate> for JulianDay {
+    fn from(date: NaiveDate) -> Self {
+        Self::from_naive_date(date)
+    }
+}
+
+impl Add<i64> for JulianDay {
+    type Output = JulianDay;
+
+    fn add(self, days: i64) -> JulianDay {
+        JulianDay(self.0 + days)
+    }
+}
+
+impl AddAssign<i64> for JulianDay {
+    fn add_assign(&mut self, days: i64) {
+        self.0 += days;
+    }
+}
+
+impl Sub<i64> for JulianDay {
+    type Output = JulianDay;
+
+    fn sub(self, days: i64) -> JulianDay {
+        JulianDay(self.0 - days)
+    }
+}
+
+impl SubAssign<i64> for JulianDay {
+    fn sub_assign(&mut self, days: i64) {
+        self.0 -= days;
+    }
+}
+
+impl Sub<JulianDay> for JulianDay {
+    type Output = i64;
+
+    fn sub(self, other: JulianDay) -> i64 {
+        self.days_since(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solar_date_test() {
+        let jd = JulianDay::from(SolarDate::new(2024, 2, 2));
+        assert_eq!(JulianDay(2460343), jd);
+        assert_eq!("2024-02-02", jd.to_solar_date().to_string());
+        assert_eq!(Weekday::Fri, jd.weekday());
+        assert_eq!("Giáp Thìn", (jd + 8).can_chi().to_string());
+
+        // 4 October 1582 (Julian) was followed by 15 October 1582 (Gregorian)
+        let reform = JulianDay::from(SolarDate::new(1582, 10, 15));
+        assert_eq!(JulianDay(GREGORIAN_REFORM), reform);
+        assert_eq!("1582-10-04", (reform - 1).to_solar_date().to_string());
+        assert_eq!(
+            "1582-10-14",
+            (reform - 1)
+                .to_solar_date_with_cutover(Cutover::ProlepticGregorian)
+                .to_string()
+        );
+        assert_eq!(
+            JulianDay(2299160),
+            JulianDay::from_solar_date_with_cutover(
+                SolarDate::new(1582, 10, 14),
+                Cutover::ProlepticGregorian
+            )
+        );
+
+        // Russia kept the Julian calendar until 1918
+        let russia = Cutover::At(JulianDay::from(SolarDate::new(1918, 2, 14)).value());
+        let day = JulianDay::from_solar_date_with_cutover(SolarDate::new(1918, 1, 31), russia);
+        assert_eq!("1918-02-13", day.to_solar_date().to_string());
+        assert_eq!(
+            "1918-01-31",
+            day.to_solar_date_with_cutover(russia).to_string()
+        );
+    }
+
+    #[test]
+    fn naive_date_test() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 2).unwrap();
+        let jd = JulianDay::from(date);
+        assert_eq!(JulianDay(2460343), jd);
+        assert_eq!(Some(date), jd.to_naive_date());
+
+        // chrono is proleptic Gregorian
+        let date = NaiveDate::from_ymd_opt(1000, 1, 1).unwrap();
+        let jd = JulianDay::from(date);
+        assert_eq!(
+            jd,
+            JulianDay::from_solar_date_with_cutover(
+                SolarDate::new(1000, 1, 1),
+                Cutover::ProlepticGregorian
+            )
+        );
+        assert_eq!(date.weekday(), jd.weekday());
+        assert_eq!(None, JulianDay(i64::MAX).to_naive_date());
+    }
+
+    #[test]
+    fn lunar_date_test() {
+        let jd = JulianDay::from_lunar_date(LunarDate::new(2024, 1, 1, false), 7).unwrap();
+        assert_eq!(JulianDay::from(SolarDate::new(2024, 2, 10)), jd);
+        assert_eq!("2024-01-01", jd.to_lunar_date(7).to_string());
+        assert_eq!("2023-12-30", (jd - 1).to_lunar_date(7).to_string());
+        assert!(JulianDay::from_lunar_date(LunarDate::new(2024, 1, 30, false), 7).is_err());
+    }
+
+    #[test]
+    fn arithmetic_test() {
+        let tet = JulianDay::from(SolarDate::new(2024, 2, 10));
+        let new_year = JulianDay::from(SolarDate::new(2024, 1, 1));
+        assert_eq!(40, tet - new_year);
+        assert_eq!(-40, new_year.days_since(tet));
+        assert_eq!(tet, new_year + 40);
+        assert!(new_year < tet);
+
+        let mut day = new_year;
+        day += 366;
+        assert_eq!("2025-01-01", day.to_solar_date().to_string());
+        day -= 1;
+        assert_eq!("2024-12-31", day.to_solar_date().to_string());
+    }
+}
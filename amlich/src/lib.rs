@@ -1,8 +1,37 @@
-use std::fmt;
+use std::{cmp::Ordering, fmt};
+pub mod calendar_system;
+pub use calendar_system::{CalendarSystem, CALENDAR_SYSTEMS};
+pub mod canchi;
+pub use canchi::{CanChi, EarthlyBranch, HeavenlyStem};
+mod errors;
+pub use errors::{LunarDateError, ParseDateError, MAX_YEAR, MIN_YEAR};
 mod fns;
+pub mod julian_day;
+pub use julian_day::{Cutover, JulianDay, GREGORIAN_REFORM};
+pub mod moon;
+pub mod nap_am;
+pub use nap_am::{Element, NapAm};
+#[cfg_attr(feature = "precise", allow(dead_code))]
+mod table;
+// compiled without the feature as well so both models can be compared in tests
+#[cfg_attr(not(feature = "precise"), allow(dead_code))]
+mod precise;
+pub use moon::{moon_phases, moon_state, MoonPhase, MoonPhaseTime, MoonState};
+pub mod time_zone;
+pub use time_zone::{TimeZone, TimeZoneRule, VietnamRegion};
+pub mod lunar_year;
+pub use lunar_year::{days_in_lunar_month, lunar_year_info, LunarMonthInfo, LunarYearInfo};
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "serde")]
+pub use serde_impl::structured;
+mod parse;
+pub use parse::{parse_lunar_date, parse_solar_date};
+pub mod solar_terms;
 use fns::{get_leap_month_offset, get_lunar_month11, get_new_moon_day, jd_to_date};
+pub use solar_terms::{solar_term_of_day, solar_terms_of_year, SolarTerm};
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct SolarDate {
     pub day: u32,
     pub month: u32,
@@ -26,7 +55,19 @@ impl fmt::Display for SolarDate {
     }
 }
 
-#[derive(Copy, Clone)]
+impl Ord for SolarDate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.year, self.month, self.day).cmp(&(other.year, other.month, other.day))
+    }
+}
+
+impl PartialOrd for SolarDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct LunarDate {
     pub day: u32,
     pub month: u32,
@@ -45,6 +86,24 @@ impl fmt::Display for LunarDate {
     }
 }
 
+/// A leap month comes after the regular month it repeats
+impl Ord for LunarDate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.year, self.month, self.is_leap, self.day).cmp(&(
+            other.year,
+            other.month,
+            other.is_leap,
+            other.day,
+        ))
+    }
+}
+
+impl PartialOrd for LunarDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl LunarDate {
     pub fn new(year: i32, month: u32, day: u32, is_leap: bool) -> Self {
         Self {
@@ -56,14 +115,27 @@ impl LunarDate {
     }
 }
 
-pub fn solar2lunar(solar_date: SolarDate, time_zone: i64) -> LunarDate {
+// Conversions in UTC+7 are read from a precomputed table, unless the precise model is
+// enabled which the table does not follow
+fn use_table(time_zone: &dyn TimeZoneRule) -> bool {
+    !cfg!(feature = "precise")
+        && time_zone.fixed_offset_minutes() == Some(table::TABLE_OFFSET_MINUTES)
+}
+
+pub fn solar2lunar(solar_date: SolarDate, time_zone: impl TimeZoneRule) -> LunarDate {
+    let time_zone = &time_zone as &dyn TimeZoneRule;
     let yyyy = solar_date.year as i64;
     let mm = solar_date.month as i64;
     let dd = solar_date.day as i64;
 
     let day_number = fns::jd_from_date(dd, mm, yyyy);
+    if use_table(time_zone) {
+        if let Some(lunar_date) = table::solar2lunar(day_number) {
+            return lunar_date;
+        }
+    }
 
-    let k = ((day_number as f64 - 2415021.076998695) / 29.530588853) as i64;
+    let k = fns::new_moon_index(day_number);
     let mut month_start = get_new_moon_day(k + 1, time_zone);
     if month_start > day_number {
         month_start = get_new_moon_day(k, time_zone);
@@ -107,10 +179,75 @@ pub fn solar2lunar(solar_date: SolarDate, time_zone: i64) -> LunarDate {
     );
 }
 
-pub fn lunar2solar(luna_date: LunarDate, time_zone: i64) -> SolarDate {
+/// Convert a lunar date to solar date.
+/// Returns `SolarDate::new(0, 0, 0)` for a leap month the year does not have. The year,
+/// month and day are not checked, use `try_lunar2solar` to validate them.
+pub fn lunar2solar(luna_date: LunarDate, time_zone: impl TimeZoneRule) -> SolarDate {
+    let time_zone = &time_zone as &dyn TimeZoneRule;
+    if let Ok(solar_date) = checked_lunar2solar(luna_date, time_zone) {
+        return solar_date;
+    }
+
+    match lunar_month_start(luna_date, time_zone) {
+        Some((month_start, _, _)) => jd_to_date(month_start + luna_date.day as i64 - 1),
+        None => SolarDate::new(0, 0, 0),
+    }
+}
+
+pub fn try_lunar2solar(
+    luna_date: LunarDate,
+    time_zone: impl TimeZoneRule,
+) -> Result<SolarDate, LunarDateError> {
+    checked_lunar2solar(luna_date, &time_zone)
+}
+
+fn checked_lunar2solar(
+    luna_date: LunarDate,
+    time_zone: &dyn TimeZoneRule,
+) -> Result<SolarDate, LunarDateError> {
+    if !(MIN_YEAR..=MAX_YEAR).contains(&luna_date.year) {
+        return Err(LunarDateError::YearOutOfRange(luna_date.year));
+    }
+    if !(1..=12).contains(&luna_date.month) {
+        return Err(LunarDateError::MonthOutOfRange(luna_date.month));
+    }
+
+    if use_table(time_zone) {
+        if let Some(result) = table::lunar2solar(luna_date) {
+            return result;
+        }
+    }
+
+    let no_such_leap_month = LunarDateError::NoSuchLeapMonth {
+        year: luna_date.year,
+        month: luna_date.month,
+    };
+    let (month_start, days_in_month, has_leap_month) =
+        lunar_month_start(luna_date, time_zone).ok_or(no_such_leap_month)?;
+    if luna_date.is_leap && !has_leap_month {
+        return Err(no_such_leap_month);
+    }
+
+    let lunar_day = luna_date.day as i64;
+    if lunar_day < 1 || lunar_day > days_in_month {
+        return Err(LunarDateError::DayOutOfRange {
+            day: luna_date.day,
+            days_in_month: days_in_month as u32,
+        });
+    }
+
+    Ok(jd_to_date(month_start + lunar_day - 1))
+}
+
+// Julian day number the lunar month starts on, its number of days and whether the year
+// has a leap month. None for a leap month other than the leap month of the year, the
+// leap flag is ignored in a year without one.
+fn lunar_month_start(
+    luna_date: LunarDate,
+    time_zone: &dyn TimeZoneRule,
+) -> Option<(i64, i64, bool)> {
     let lunar_year = luna_date.year as i64;
     let lunar_month = luna_date.month as i64;
-    let lunar_day = luna_date.day as i64;
     let lunar_leap: i64 = luna_date.is_leap.into();
 
     let a11: i64;
@@ -123,13 +260,14 @@ pub fn lunar2solar(luna_date: LunarDate, time_zone: i64) -> SolarDate {
         a11 = get_lunar_month11(lunar_year, time_zone);
         b11 = get_lunar_month11(lunar_year + 1, time_zone);
     }
-    let k = (0.5 + (a11 as f64 - 2415021.076998695) / 29.530588853) as i64;
+    let k = fns::nearest_new_moon_index(a11);
     let mut off = lunar_month - 11;
 
     if off < 0 {
         off += 12;
     }
-    if b11 - a11 > 365 {
+    let has_leap_month = b11 - a11 > 365;
+    if has_leap_month {
         let leap_off = get_leap_month_offset(a11, time_zone);
         let mut leap_month = leap_off - 2;
         if leap_month < 0 {
@@ -137,14 +275,15 @@ pub fn lunar2solar(luna_date: LunarDate, time_zone: i64) -> SolarDate {
         }
 
         if lunar_leap != 0 && lunar_month != leap_month {
-            return SolarDate::new(0, 0, 0);
+            return None;
         } else if lunar_leap != 0 || off >= leap_off {
             off += 1;
         }
     }
     let month_start = get_new_moon_day(k + off, time_zone);
+    let days_in_month = get_new_moon_day(k + off + 1, time_zone) - month_start;
 
-    return jd_to_date(month_start + lunar_day - 1);
+    Some((month_start, days_in_month, has_leap_month))
 }
 
 #[cfg(test)]
@@ -226,4 +365,157 @@ mod tests {
         assert_eq!(result.month, 5);
         assert_eq!(result.year, 2012);
     }
+
+    #[test]
+    fn solar2lunar_leap_month_1998_test() {
+        let result = solar2lunar(SolarDate::new(1998, 7, 22), 7);
+        assert_eq!(LunarDate::new(1998, 5, 29, true), result);
+        let result = solar2lunar(SolarDate::new(1998, 6, 23), 7);
+        assert_eq!(LunarDate::new(1998, 5, 29, false), result);
+        let result = lunar2solar(LunarDate::new(1998, 5, 29, true), 7);
+        assert_eq!(SolarDate::new(1998, 7, 22), result);
+    }
+
+    #[test]
+    fn solar2lunar_before_2000_test() {
+        // UTC+7 without a fixed offset, so the table is skipped
+        struct Astronomical;
+
+        impl TimeZoneRule for Astronomical {
+            fn offset_minutes(&self, _jd: i64) -> i64 {
+                7 * 60
+            }
+        }
+
+        // Tết 1985 a month before China, truncating put it on 1985-02-20
+        let result = solar2lunar(SolarDate::new(1985, 1, 21), Astronomical);
+        assert_eq!(LunarDate::new(1985, 1, 1, false), result);
+        // truncating gave day -9 of month 12
+        let result = solar2lunar(SolarDate::new(1899, 1, 31), Astronomical);
+        assert_eq!(LunarDate::new(1898, 12, 20, false), result);
+        let result = solar2lunar(SolarDate::new(1899, 2, 10), Astronomical);
+        assert_eq!(LunarDate::new(1899, 1, 1, false), result);
+    }
+
+    #[test]
+    fn lunar2solar_unchecked_test() {
+        // out of the range of try_lunar2solar but still converted
+        let result = lunar2solar(LunarDate::new(1100, 1, 1, false), 7);
+        assert_eq!(SolarDate::new(1100, 2, 12), result);
+        // 2024-12 has 29 days, 30 Chap runs into the next month
+        let result = lunar2solar(LunarDate::new(2024, 12, 30, false), 7);
+        assert_eq!(SolarDate::new(2025, 1, 29), result);
+        // 2024 has no leap month, the flag is ignored
+        let result = lunar2solar(LunarDate::new(2024, 6, 1, true), 7);
+        assert_eq!(SolarDate::new(2024, 7, 6), result);
+        let result = lunar2solar(LunarDate::new(2006, 6, 1, true), 7);
+        assert_eq!(SolarDate::new(0, 0, 0), result);
+    }
+
+    #[test]
+    fn try_lunar2solar_test() {
+        let result = try_lunar2solar(LunarDate::new(2006, 7, 20, true), 7).unwrap();
+        assert_eq!(result.day, 12);
+        assert_eq!(result.month, 9);
+        assert_eq!(result.year, 2006);
+        // 2024-12 has 29 days, 30 Chap does not exist
+        let result = try_lunar2solar(LunarDate::new(2024, 12, 29, false), 7).unwrap();
+        assert_eq!(result.day, 28);
+        assert_eq!(result.month, 1);
+        assert_eq!(result.year, 2025);
+    }
+
+    #[test]
+    fn try_lunar2solar_error_test() {
+        assert_eq!(
+            Some(LunarDateError::NoSuchLeapMonth {
+                year: 2006,
+                month: 6
+            }),
+            try_lunar2solar(LunarDate::new(2006, 6, 20, true), 7).err()
+        );
+        assert_eq!(
+            Some(LunarDateError::NoSuchLeapMonth {
+                year: 2024,
+                month: 7
+            }),
+            try_lunar2solar(LunarDate::new(2024, 7, 20, true), 7).err()
+        );
+        assert_eq!(
+            Some(LunarDateError::DayOutOfRange {
+                day: 30,
+                days_in_month: 29
+            }),
+            try_lunar2solar(LunarDate::new(2024, 12, 30, false), 7).err()
+        );
+        assert_eq!(
+            Some(LunarDateError::DayOutOfRange {
+                day: 0,
+                days_in_month: 29
+            }),
+            try_lunar2solar(LunarDate::new(2024, 1, 0, false), 7).err()
+        );
+        assert_eq!(
+            Some(LunarDateError::MonthOutOfRange(13)),
+            try_lunar2solar(LunarDate::new(2024, 13, 1, false), 7).err()
+        );
+        assert_eq!(
+            Some(LunarDateError::YearOutOfRange(2200)),
+            try_lunar2solar(LunarDate::new(2200, 1, 1, false), 7).err()
+        );
+    }
+
+    #[test]
+    fn ordering_test() {
+        let mut dates = vec![
+            LunarDate::new(2006, 8, 1, false),
+            LunarDate::new(2006, 7, 20, true),
+            LunarDate::new(2007, 1, 1, false),
+            LunarDate::new(2006, 7, 29, false),
+            LunarDate::new(2006, 7, 1, true),
+        ];
+        dates.sort();
+        let dates: Vec<String> = dates.iter().map(|d| d.to_string()).collect();
+        assert_eq!(
+            vec![
+                "2006-07-29",
+                "2006-07-01L",
+                "2006-07-20L",
+                "2006-08-01",
+                "2007-01-01"
+            ],
+            dates
+        );
+
+        assert!(SolarDate::new(2024, 1, 31) < SolarDate::new(2024, 2, 1));
+        assert!(SolarDate::new(2023, 12, 31) < SolarDate::new(2024, 1, 1));
+        assert_eq!(SolarDate::new(2024, 2, 10), SolarDate::new(2024, 2, 10));
+    }
+
+    #[test]
+    fn lunar2solar_invalid_date_test() {
+        // 2033 has a leap month 11 after the winter solstice, month 12 is not leap
+        let result = lunar2solar(LunarDate::new(2033, 11, 1, true), 7);
+        assert_eq!(SolarDate::new(2033, 12, 22), result);
+        let result = lunar2solar(LunarDate::new(2033, 12, 1, true), 7);
+        assert_eq!(result.day, 0);
+        assert_eq!(result.month, 0);
+        assert_eq!(result.year, 0);
+    }
+
+    #[test]
+    fn tet_1968_by_region_test() {
+        let north = lunar2solar(LunarDate::new(1968, 1, 1, false), VietnamRegion::North);
+        assert_eq!("1968-01-29", north.to_string());
+        let south = lunar2solar(LunarDate::new(1968, 1, 1, false), VietnamRegion::South);
+        assert_eq!("1968-01-30", south.to_string());
+
+        let result = solar2lunar(SolarDate::new(1968, 1, 29), VietnamRegion::South);
+        assert_eq!(1967, result.year);
+        assert_eq!(12, result.month);
+        let result = solar2lunar(SolarDate::new(1968, 1, 29), VietnamRegion::North);
+        assert_eq!(1968, result.year);
+        assert_eq!(1, result.month);
+        assert_eq!(1, result.day);
+    }
 }
@@ -0,0 +1,301 @@
+use super::{
+    errors::{LunarDateError, MAX_YEAR, MIN_YEAR},
+    fns::{
+        get_leap_month_offset, get_lunar_month11, get_new_moon_day, jd_to_date,
+        nearest_new_moon_index,
+    },
+    table,
+    time_zone::TimeZoneRule,
+    use_table, SolarDate,
+};
+
+#[derive(Copy, Clone)]
+pub struct LunarMonthInfo {
+    pub month: u32,
+    pub is_leap: bool,
+    /// Solar date of the first day of the month
+    pub start: SolarDate,
+    /// 29 or 30
+    pub days: u32,
+}
+
+#[derive(Clone)]
+pub struct LunarYearInfo {
+    pub year: i32,
+    pub leap_month: Option<u32>,
+    /// All months of the year in chronological order, 12 or 13 with a leap month
+    pub months: Vec<LunarMonthInfo>,
+}
+
+impl LunarYearInfo {
+    pub fn month(&self, month: u32, is_leap: bool) -> Option<&LunarMonthInfo> {
+        self.months
+            .iter()
+            .find(|m| m.month == month && m.is_leap == is_leap)
+    }
+
+    pub fn days(&self) -> u32 {
+        self.months.iter().map(|m| m.days).sum()
+    }
+}
+
+struct MonthSpan {
+    month: u32,
+    is_leap: bool,
+    start: i64,
+    days: i64,
+}
+
+// Months from lunar month 11 of the given solar year up to, but not including,
+// month 11 of the next year
+fn months_from_month11(yyyy: i64, time_zone: &dyn TimeZoneRule) -> Vec<MonthSpan> {
+    let a11 = get_lunar_month11(yyyy, time_zone);
+    let b11 = get_lunar_month11(yyyy + 1, time_zone);
+    let k = nearest_new_moon_index(a11);
+
+    let leap_off = if b11 - a11 > 365 {
+        Some(get_leap_month_offset(a11, time_zone))
+    } else {
+        None
+    };
+    let count = if leap_off.is_some() { 13 } else { 12 };
+
+    let mut months = vec![];
+    for i in 0..count {
+        let start = get_new_moon_day(k + i, time_zone);
+        let end = get_new_moon_day(k + i + 1, time_zone);
+        // month offset from month 11, the leap month repeats the previous one
+        let (off, is_leap) = match leap_off {
+            Some(leap_off) if i == leap_off => (i - 1, true),
+            Some(leap_off) if i > leap_off => (i - 1, false),
+            _ => (i, false),
+        };
+        months.push(MonthSpan {
+            month: ((off + 10) % 12 + 1) as u32,
+            is_leap,
+            start,
+            days: end - start,
+        });
+    }
+
+    months
+}
+
+/// Structure of the given lunar year: its leap month if any, and start and length
+/// of every month
+pub fn lunar_year_info(
+    year: i32,
+    time_zone: impl TimeZoneRule,
+) -> Result<LunarYearInfo, LunarDateError> {
+    if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+        return Err(LunarDateError::YearOutOfRange(year));
+    }
+
+    let time_zone = &time_zone as &dyn TimeZoneRule;
+    if use_table(time_zone) {
+        if let Some(entries) = table::months(year) {
+            let months = entries
+                .into_iter()
+                .map(|entry| LunarMonthInfo {
+                    month: entry.month,
+                    is_leap: entry.is_leap,
+                    start: jd_to_date(entry.start),
+                    days: entry.days as u32,
+                })
+                .collect();
+            return Ok(year_info(year, months));
+        }
+    }
+
+    Ok(astronomical_year_info(year, time_zone))
+}
+
+// Same as lunar_year_info with the astronomical model, for any year
+pub(crate) fn astronomical_year_info(year: i32, time_zone: &dyn TimeZoneRule) -> LunarYearInfo {
+    let yyyy = year as i64;
+    let mut months: Vec<LunarMonthInfo> = vec![];
+
+    // Months 1-10 follow month 11 of the previous solar year, months 11 and 12
+    // follow month 11 of this solar year
+    let first = months_from_month11(yyyy - 1, time_zone);
+    let second = months_from_month11(yyyy, time_zone);
+    let spans = first
+        .into_iter()
+        .filter(|m| m.month < 11)
+        .chain(second.into_iter().filter(|m| m.month >= 11));
+
+    for span in spans {
+        months.push(LunarMonthInfo {
+            month: span.month,
+            is_leap: span.is_leap,
+            start: jd_to_date(span.start),
+            days: span.days as u32,
+        });
+    }
+
+    year_info(year, months)
+}
+
+fn year_info(year: i32, months: Vec<LunarMonthInfo>) -> LunarYearInfo {
+    LunarYearInfo {
+        year,
+        leap_month: months.iter().find(|m| m.is_leap).map(|m| m.month),
+        months,
+    }
+}
+
+pub fn days_in_lunar_month(
+    year: i32,
+    month: u32,
+    is_leap: bool,
+    time_zone: impl TimeZoneRule,
+) -> Result<u32, LunarDateError> {
+    if !(1..=12).contains(&month) {
+        return Err(LunarDateError::MonthOutOfRange(month));
+    }
+
+    match lunar_year_info(year, time_zone)?.month(month, is_leap) {
+        Some(info) => Ok(info.days),
+        None => Err(LunarDateError::NoSuchLeapMonth { year, month }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn start(info: &LunarMonthInfo) -> String {
+        info.start.to_string()
+    }
+
+    #[test]
+    fn lunar_year_info_test() {
+        let info = lunar_year_info(2024, 7).unwrap();
+        assert_eq!(None, info.leap_month);
+        assert_eq!(12, info.months.len());
+        assert_eq!("2024-02-10", start(&info.months[0]));
+        assert_eq!(29, info.months[0].days);
+        assert_eq!("2024-12-31", start(&info.months[11]));
+        assert_eq!(29, info.months[11].days);
+        assert_eq!(354, info.days());
+        for (i, month) in info.months.iter().enumerate() {
+            assert_eq!(i as u32 + 1, month.month);
+        }
+    }
+
+    #[test]
+    fn lunar_year_info_out_of_range_test() {
+        for year in [MIN_YEAR - 1, MAX_YEAR + 1] {
+            assert_eq!(
+                Some(LunarDateError::YearOutOfRange(year)),
+                lunar_year_info(year, 7).err()
+            );
+        }
+        assert!(lunar_year_info(MIN_YEAR, 7).is_ok());
+    }
+
+    #[test]
+    fn lunar_year_info_leap_test() {
+        let info = lunar_year_info(2025, 7).unwrap();
+        assert_eq!(Some(6), info.leap_month);
+        assert_eq!(13, info.months.len());
+        assert_eq!("2025-01-29", start(&info.months[0]));
+        let leap = info.month(6, true).unwrap();
+        assert_eq!("2025-07-25", start(leap));
+        assert_eq!(29, leap.days);
+        assert_eq!(6, info.months[5].month);
+        assert!(!info.months[5].is_leap);
+        assert_eq!(6, info.months[6].month);
+        assert!(info.months[6].is_leap);
+        assert_eq!(7, info.months[7].month);
+    }
+
+    #[test]
+    fn lunar_year_info_leap_month_11_test() {
+        let info = lunar_year_info(2033, 7).unwrap();
+        assert_eq!(Some(11), info.leap_month);
+        assert_eq!(13, info.months.len());
+        assert_eq!(11, info.months[11].month);
+        assert!(info.months[11].is_leap);
+        assert_eq!(12, info.months[12].month);
+
+        assert_eq!(None, lunar_year_info(2034, 7).unwrap().leap_month);
+        assert_eq!(12, lunar_year_info(2034, 7).unwrap().months.len());
+    }
+
+    #[test]
+    fn known_leap_months_test() {
+        let leap_months = [
+            (1881, 7),
+            (1884, 5),
+            (1887, 4),
+            (1890, 2),
+            (1892, 6),
+            (1895, 5),
+            (1898, 3),
+            (1900, 8),
+            (1990, 5),
+            (1993, 3),
+            (1995, 8),
+            (1998, 5),
+            (2001, 4),
+            (2004, 2),
+            (2006, 7),
+            (2009, 5),
+            (2012, 4),
+            (2014, 9),
+            (2017, 6),
+            (2020, 4),
+            (2023, 2),
+        ];
+        for (year, month) in leap_months {
+            assert_eq!(
+                Some(month),
+                lunar_year_info(year, 7).unwrap().leap_month,
+                "{}",
+                year
+            );
+        }
+        for year in [1899, 1997, 1999, 2000, 2024] {
+            assert_eq!(
+                None,
+                lunar_year_info(year, 7).unwrap().leap_month,
+                "{}",
+                year
+            );
+        }
+    }
+
+    #[test]
+    fn lunar_year_info_matches_lunar2solar_test() {
+        for year in [2006, 2012, 2020, 2023, 2033] {
+            for info in lunar_year_info(year, 7).unwrap().months {
+                let lunar = crate::LunarDate::new(year, info.month, 1, info.is_leap);
+                assert_eq!(
+                    start(&info),
+                    crate::lunar2solar(lunar, 7).to_string(),
+                    "{}",
+                    lunar
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn days_in_lunar_month_test() {
+        assert_eq!(Ok(29), days_in_lunar_month(2024, 12, false, 7));
+        assert_eq!(Ok(30), days_in_lunar_month(2023, 12, false, 7));
+        assert_eq!(Ok(29), days_in_lunar_month(2025, 6, true, 7));
+        assert_eq!(
+            Err(LunarDateError::NoSuchLeapMonth {
+                year: 2024,
+                month: 6
+            }),
+            days_in_lunar_month(2024, 6, true, 7)
+        );
+        assert_eq!(
+            Err(LunarDateError::MonthOutOfRange(0)),
+            days_in_lunar_month(2024, 0, false, 7)
+        );
+    }
+}
@@ -0,0 +1,296 @@
+use std::f64::consts::PI;
+use std::fmt;
+
+use chrono::{DateTime, Datelike, FixedOffset, Utc};
+
+use super::{
+    fns::{delta_t_days, jd_from_date, jd_to_datetime},
+    time_zone::TimeZoneRule,
+    SolarDate,
+};
+
+const SYNODIC_MONTH: f64 = 29.530588861;
+// Julian date of the new moon of 6 January 2000, phase number k = 0
+const FIRST_NEW_MOON_2000: f64 = 2451550.09766;
+const UNIX_EPOCH_JD: f64 = 2440587.5;
+
+/// The four principal phases of the moon
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MoonPhase {
+    NewMoon = 0,
+    FirstQuarter = 1,
+    FullMoon = 2,
+    LastQuarter = 3,
+}
+
+pub const MOON_PHASES: [MoonPhase; 4] = [
+    MoonPhase::NewMoon,
+    MoonPhase::FirstQuarter,
+    MoonPhase::FullMoon,
+    MoonPhase::LastQuarter,
+];
+
+impl MoonPhase {
+    #[inline]
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            MoonPhase::NewMoon => "Trăng non",
+            MoonPhase::FirstQuarter => "Thượng huyền",
+            MoonPhase::FullMoon => "Trăng tròn",
+            MoonPhase::LastQuarter => "Hạ huyền",
+        }
+    }
+}
+
+impl fmt::Display for MoonPhase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MoonPhaseTime {
+    pub phase: MoonPhase,
+    pub time: DateTime<FixedOffset>,
+}
+
+/// Position of the moon in its cycle at an instant
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MoonState {
+    /// Fraction of the synodic month, 0 is new moon, 0.25 first quarter,
+    /// 0.5 full moon and 0.75 last quarter
+    pub phase: f64,
+    /// Illuminated fraction of the disk, from 0 to 1
+    pub illumination: f64,
+}
+
+impl MoonState {
+    #[inline]
+    pub fn is_waxing(&self) -> bool {
+        self.phase < 0.5
+    }
+}
+
+#[inline]
+fn sin(degrees: f64) -> f64 {
+    (degrees * PI / 180.0).sin()
+}
+
+#[inline]
+fn cos(degrees: f64) -> f64 {
+    (degrees * PI / 180.0).cos()
+}
+
+// Dynamical time (julian ephemeris date) of the phase of lunation k, k = 0 is the first
+// new moon of 2000. Astronomical Algorithms (Meeus), chapter 49, without planetary arguments.
+pub(crate) fn phase_jde(k: f64, phase: MoonPhase) -> f64 {
+    let k = k + phase.index() as f64 / 4.0;
+    let t = k / 1236.85;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let t4 = t3 * t;
+
+    let jde = FIRST_NEW_MOON_2000 + SYNODIC_MONTH * k + 0.00015437 * t2 - 0.00000015 * t3
+        + 0.00000000073 * t4;
+    let e = 1.0 - 0.002516 * t - 0.0000074 * t2;
+    let m = 2.5534 + 29.1053567 * k - 0.0000014 * t2 - 0.00000011 * t3; // Sun's mean anomaly
+    let mp = 201.5643 + 385.81693528 * k + 0.0107582 * t2 + 0.00001238 * t3 - 0.000000058 * t4; // Moon's mean anomaly
+    let f = 160.7108 + 390.67050284 * k - 0.0016118 * t2 - 0.00000227 * t3 + 0.000000011 * t4; // Moon's argument of latitude
+    let omega = 124.7746 - 1.56375588 * k + 0.0020672 * t2 + 0.00000215 * t3;
+
+    let correction = match phase {
+        MoonPhase::NewMoon | MoonPhase::FullMoon => {
+            let (c1, c2, c3, c4, c5, c6, c7) = match phase {
+                MoonPhase::NewMoon => (
+                    -0.4072, 0.17241, 0.01608, 0.01039, 0.00739, -0.00514, 0.00208,
+                ),
+                _ => (
+                    -0.40614, 0.17302, 0.01614, 0.01043, 0.00734, -0.00515, 0.00209,
+                ),
+            };
+            c1 * sin(mp)
+                + c2 * e * sin(m)
+                + c3 * sin(2.0 * mp)
+                + c4 * sin(2.0 * f)
+                + c5 * e * sin(mp - m)
+                + c6 * e * sin(mp + m)
+                + c7 * e * e * sin(2.0 * m)
+                - 0.00111 * sin(mp - 2.0 * f)
+                - 0.00057 * sin(mp + 2.0 * f)
+                + 0.00056 * e * sin(2.0 * mp + m)
+                - 0.00042 * sin(3.0 * mp)
+                + 0.00042 * e * sin(m + 2.0 * f)
+                + 0.00038 * e * sin(m - 2.0 * f)
+                - 0.00024 * e * sin(2.0 * mp - m)
+                - 0.00017 * sin(omega)
+        }
+        MoonPhase::FirstQuarter | MoonPhase::LastQuarter => {
+            let w = 0.00306 - 0.00038 * e * cos(m) + 0.00026 * cos(mp) - 0.00002 * cos(mp - m)
+                + 0.00002 * cos(mp + m)
+                + 0.00002 * cos(2.0 * f);
+            let w = if phase == MoonPhase::FirstQuarter {
+                w
+            } else {
+                -w
+            };
+            -0.62801 * sin(mp) + 0.17172 * e * sin(m) - 0.01183 * e * sin(mp + m)
+                + 0.00862 * sin(2.0 * mp)
+                + 0.00804 * sin(2.0 * f)
+                + 0.00454 * e * sin(mp - m)
+                + 0.00204 * e * e * sin(2.0 * m)
+                - 0.0018 * sin(mp - 2.0 * f)
+                - 0.0007 * sin(mp + 2.0 * f)
+                - 0.0004 * sin(3.0 * mp)
+                - 0.00034 * e * sin(2.0 * mp - m)
+                + 0.00032 * e * sin(m + 2.0 * f)
+                + 0.00032 * e * sin(m - 2.0 * f)
+                - 0.00028 * e * e * sin(mp + 2.0 * m)
+                + 0.00027 * e * sin(2.0 * mp + m)
+                - 0.00017 * sin(omega)
+                + w
+        }
+    };
+
+    jde + correction
+}
+
+// Instant (julian date, UT) of the phase of lunation k
+fn phase_time(k: i64, phase: MoonPhase) -> f64 {
+    let jde = phase_jde(k as f64, phase);
+    jde - delta_t_days(jde)
+}
+
+/// Instants of the principal moon phases whose local date is between from and to (inclusive)
+pub fn moon_phases(
+    from: SolarDate,
+    to: SolarDate,
+    time_zone: impl TimeZoneRule,
+) -> Vec<MoonPhaseTime> {
+    let time_zone = &time_zone as &dyn TimeZoneRule;
+    let from_jd = jd_from_date(from.day as i64, from.month as i64, from.year as i64);
+    let to_jd = jd_from_date(to.day as i64, to.month as i64, to.year as i64);
+
+    let mut phases = vec![];
+    // start one lunation early, local dates are at most a day away from UT
+    let mut k = ((from_jd as f64 - FIRST_NEW_MOON_2000) / SYNODIC_MONTH).floor() as i64 - 1;
+    loop {
+        for phase in MOON_PHASES {
+            let jd = phase_time(k, phase);
+            if jd > to_jd as f64 + 1.0 {
+                return phases;
+            }
+
+            let time = jd_to_datetime(jd, time_zone);
+            let local_date = time.date_naive();
+            let local_jd = jd_from_date(
+                local_date.day() as i64,
+                local_date.month() as i64,
+                local_date.year() as i64,
+            );
+            if (from_jd..=to_jd).contains(&local_jd) {
+                phases.push(MoonPhaseTime { phase, time });
+            }
+        }
+        k += 1;
+    }
+}
+
+/// Phase and illuminated fraction of the moon at the given instant.
+/// Astronomical Algorithms (Meeus), chapter 48, accurate to about a degree of elongation.
+pub fn moon_state(time: DateTime<Utc>) -> MoonState {
+    let jd = UNIX_EPOCH_JD + time.timestamp() as f64 / 86400.0;
+    let t = (jd - 2451545.0) / 36525.0;
+
+    let d = 297.8501921 + 445267.1114034 * t - 0.0018819 * t * t; // Moon's mean elongation
+    let m = 357.5291092 + 35999.0502909 * t - 0.0001536 * t * t; // Sun's mean anomaly
+    let mp = 134.9633964 + 477198.8675055 * t + 0.0087414 * t * t; // Moon's mean anomaly
+
+    // 180° minus the phase angle
+    let elongation = d + 6.289 * sin(mp) - 2.1 * sin(m)
+        + 1.274 * sin(2.0 * d - mp)
+        + 0.658 * sin(2.0 * d)
+        + 0.214 * sin(2.0 * mp)
+        + 0.11 * sin(d);
+    let elongation = elongation.rem_euclid(360.0);
+
+    MoonState {
+        phase: elongation / 360.0,
+        illumination: (1.0 - cos(elongation)) / 2.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn assert_close(expected: &str, actual: DateTime<FixedOffset>) {
+        let expected = DateTime::parse_from_rfc3339(expected).unwrap();
+        let diff = (actual - expected).num_seconds().abs();
+        assert!(diff <= 120, "expected {} got {}", expected, actual);
+    }
+
+    #[test]
+    fn moon_phases_test() {
+        let phases = moon_phases(SolarDate::new(2024, 1, 1), SolarDate::new(2024, 1, 31), 7);
+        assert_eq!(4, phases.len());
+        assert_eq!(MoonPhase::LastQuarter, phases[0].phase);
+        assert_close("2024-01-04T10:30:00+07:00", phases[0].time);
+        assert_eq!(MoonPhase::NewMoon, phases[1].phase);
+        assert_close("2024-01-11T18:57:00+07:00", phases[1].time);
+        assert_eq!(MoonPhase::FirstQuarter, phases[2].phase);
+        assert_close("2024-01-18T10:52:00+07:00", phases[2].time);
+        assert_eq!(MoonPhase::FullMoon, phases[3].phase);
+        assert_close("2024-01-26T00:54:00+07:00", phases[3].time);
+    }
+
+    #[test]
+    fn moon_phases_local_date_test() {
+        // the full moon of 25 January 2024 17:54 UTC is on the 26th in Vietnam
+        let phases = moon_phases(SolarDate::new(2024, 1, 25), SolarDate::new(2024, 1, 25), 7);
+        assert!(phases.is_empty());
+        let phases = moon_phases(SolarDate::new(2024, 1, 25), SolarDate::new(2024, 1, 25), 0);
+        assert_eq!(1, phases.len());
+        assert_eq!(MoonPhase::FullMoon, phases[0].phase);
+    }
+
+    #[test]
+    fn new_moons_match_lunar_months_test() {
+        let phases = moon_phases(SolarDate::new(2025, 1, 1), SolarDate::new(2025, 12, 31), 7);
+        let new_moons: Vec<String> = phases
+            .iter()
+            .filter(|p| p.phase == MoonPhase::NewMoon)
+            .map(|p| p.time.format("%Y-%m-%d").to_string())
+            .collect();
+        let month_starts: Vec<String> = [
+            crate::lunar_year_info(2024, 7).unwrap(),
+            crate::lunar_year_info(2025, 7).unwrap(),
+        ]
+        .iter()
+        .flat_map(|info| info.months.iter().map(|m| m.start.to_string()))
+        .filter(|start| start.starts_with("2025"))
+        .collect();
+        assert_eq!(month_starts, new_moons);
+    }
+
+    #[test]
+    fn moon_state_test() {
+        let full = moon_state(Utc.with_ymd_and_hms(2024, 1, 25, 17, 54, 0).unwrap());
+        assert!((full.phase - 0.5).abs() < 0.01);
+        assert!(full.illumination > 0.99);
+
+        let new = moon_state(Utc.with_ymd_and_hms(2024, 1, 11, 11, 57, 0).unwrap());
+        assert!(new.phase < 0.01 || new.phase > 0.99);
+        assert!(new.illumination < 0.01);
+
+        let quarter = moon_state(Utc.with_ymd_and_hms(2024, 1, 18, 3, 52, 0).unwrap());
+        assert!((quarter.phase - 0.25).abs() < 0.01);
+        assert!((quarter.illumination - 0.5).abs() < 0.02);
+        assert!(quarter.is_waxing());
+    }
+}
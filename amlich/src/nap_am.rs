@@ -0,0 +1,276 @@
+use std::fmt;
+
+use super::canchi::CanChi;
+
+/// The five elements (ngũ hành)
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Element {
+    Kim = 0,
+    Moc = 1,
+    Thuy = 2,
+    Hoa = 3,
+    Tho = 4,
+}
+
+impl Element {
+    #[inline]
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Element::Kim => "Kim",
+            Element::Moc => "Mộc",
+            Element::Thuy => "Thủy",
+            Element::Hoa => "Hỏa",
+            Element::Tho => "Thổ",
+        }
+    }
+
+    /// Hán character of the element, e.g. 金 for Kim
+    pub fn han(&self) -> char {
+        ['金', '木', '水', '火', '土'][self.index()]
+    }
+}
+
+impl fmt::Display for Element {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The 30 nạp âm (sound elements), each shared by two consecutive pairs
+/// of the Can Chi cycle, from Hải Trung Kim for Giáp Tý and Ất Sửu to
+/// Đại Hải Thủy for Nhâm Tuất and Quý Hợi
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NapAm {
+    HaiTrungKim = 0,
+    LuTrungHoa = 1,
+    DaiLamMoc = 2,
+    LoBangTho = 3,
+    KiemPhongKim = 4,
+    SonDauHoa = 5,
+    GianHaThuy = 6,
+    ThanhDauTho = 7,
+    BachLapKim = 8,
+    DuongLieuMoc = 9,
+    TuyenTrungThuy = 10,
+    OcThuongTho = 11,
+    TichLichHoa = 12,
+    TungBachMoc = 13,
+    TruongLuuThuy = 14,
+    SaTrungKim = 15,
+    SonHaHoa = 16,
+    BinhDiaMoc = 17,
+    BichThuongTho = 18,
+    KimBachKim = 19,
+    PhuDangHoa = 20,
+    ThienHaThuy = 21,
+    DaiTrachTho = 22,
+    ThoaXuyenKim = 23,
+    TangDoMoc = 24,
+    DaiKheThuy = 25,
+    SaTrungTho = 26,
+    ThienThuongHoa = 27,
+    ThachLuuMoc = 28,
+    DaiHaiThuy = 29,
+}
+
+pub const NAP_AMS: [NapAm; 30] = [
+    NapAm::HaiTrungKim,
+    NapAm::LuTrungHoa,
+    NapAm::DaiLamMoc,
+    NapAm::LoBangTho,
+    NapAm::KiemPhongKim,
+    NapAm::SonDauHoa,
+    NapAm::GianHaThuy,
+    NapAm::ThanhDauTho,
+    NapAm::BachLapKim,
+    NapAm::DuongLieuMoc,
+    NapAm::TuyenTrungThuy,
+    NapAm::OcThuongTho,
+    NapAm::TichLichHoa,
+    NapAm::TungBachMoc,
+    NapAm::TruongLuuThuy,
+    NapAm::SaTrungKim,
+    NapAm::SonHaHoa,
+    NapAm::BinhDiaMoc,
+    NapAm::BichThuongTho,
+    NapAm::KimBachKim,
+    NapAm::PhuDangHoa,
+    NapAm::ThienHaThuy,
+    NapAm::DaiTrachTho,
+    NapAm::ThoaXuyenKim,
+    NapAm::TangDoMoc,
+    NapAm::DaiKheThuy,
+    NapAm::SaTrungTho,
+    NapAm::ThienThuongHoa,
+    NapAm::ThachLuuMoc,
+    NapAm::DaiHaiThuy,
+];
+
+impl NapAm {
+    pub fn from_can_chi(can_chi: CanChi) -> Self {
+        NAP_AMS[can_chi.index() / 2]
+    }
+
+    #[inline]
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            NapAm::HaiTrungKim => "Hải Trung Kim",
+            NapAm::LuTrungHoa => "Lư Trung Hỏa",
+            NapAm::DaiLamMoc => "Đại Lâm Mộc",
+            NapAm::LoBangTho => "Lộ Bàng Thổ",
+            NapAm::KiemPhongKim => "Kiếm Phong Kim",
+            NapAm::SonDauHoa => "Sơn Đầu Hỏa",
+            NapAm::GianHaThuy => "Giản Hạ Thủy",
+            NapAm::ThanhDauTho => "Thành Đầu Thổ",
+            NapAm::BachLapKim => "Bạch Lạp Kim",
+            NapAm::DuongLieuMoc => "Dương Liễu Mộc",
+            NapAm::TuyenTrungThuy => "Tuyền Trung Thủy",
+            NapAm::OcThuongTho => "Ốc Thượng Thổ",
+            NapAm::TichLichHoa => "Tích Lịch Hỏa",
+            NapAm::TungBachMoc => "Tùng Bách Mộc",
+            NapAm::TruongLuuThuy => "Trường Lưu Thủy",
+            NapAm::SaTrungKim => "Sa Trung Kim",
+            NapAm::SonHaHoa => "Sơn Hạ Hỏa",
+            NapAm::BinhDiaMoc => "Bình Địa Mộc",
+            NapAm::BichThuongTho => "Bích Thượng Thổ",
+            NapAm::KimBachKim => "Kim Bạch Kim",
+            NapAm::PhuDangHoa => "Phú Đăng Hỏa",
+            NapAm::ThienHaThuy => "Thiên Hà Thủy",
+            NapAm::DaiTrachTho => "Đại Trạch Thổ",
+            NapAm::ThoaXuyenKim => "Thoa Xuyến Kim",
+            NapAm::TangDoMoc => "Tang Đố Mộc",
+            NapAm::DaiKheThuy => "Đại Khê Thủy",
+            NapAm::SaTrungTho => "Sa Trung Thổ",
+            NapAm::ThienThuongHoa => "Thiên Thượng Hỏa",
+            NapAm::ThachLuuMoc => "Thạch Lựu Mộc",
+            NapAm::DaiHaiThuy => "Đại Hải Thủy",
+        }
+    }
+
+    /// Hán characters of the nạp âm, e.g. 海中金 for Hải Trung Kim
+    pub fn han(&self) -> &'static str {
+        match self {
+            NapAm::HaiTrungKim => "海中金",
+            NapAm::LuTrungHoa => "爐中火",
+            NapAm::DaiLamMoc => "大林木",
+            NapAm::LoBangTho => "路旁土",
+            NapAm::KiemPhongKim => "劍鋒金",
+            NapAm::SonDauHoa => "山頭火",
+            NapAm::GianHaThuy => "澗下水",
+            NapAm::ThanhDauTho => "城頭土",
+            NapAm::BachLapKim => "白蠟金",
+            NapAm::DuongLieuMoc => "楊柳木",
+            NapAm::TuyenTrungThuy => "泉中水",
+            NapAm::OcThuongTho => "屋上土",
+            NapAm::TichLichHoa => "霹靂火",
+            NapAm::TungBachMoc => "松柏木",
+            NapAm::TruongLuuThuy => "長流水",
+            NapAm::SaTrungKim => "沙中金",
+            NapAm::SonHaHoa => "山下火",
+            NapAm::BinhDiaMoc => "平地木",
+            NapAm::BichThuongTho => "壁上土",
+            NapAm::KimBachKim => "金箔金",
+            NapAm::PhuDangHoa => "覆燈火",
+            NapAm::ThienHaThuy => "天河水",
+            NapAm::DaiTrachTho => "大驛土",
+            NapAm::ThoaXuyenKim => "釵釧金",
+            NapAm::TangDoMoc => "桑柘木",
+            NapAm::DaiKheThuy => "大溪水",
+            NapAm::SaTrungTho => "沙中土",
+            NapAm::ThienThuongHoa => "天上火",
+            NapAm::ThachLuuMoc => "石榴木",
+            NapAm::DaiHaiThuy => "大海水",
+        }
+    }
+
+    /// Element the nạp âm belongs to, the last word of its name
+    pub fn element(&self) -> Element {
+        match self {
+            NapAm::HaiTrungKim
+            | NapAm::KiemPhongKim
+            | NapAm::BachLapKim
+            | NapAm::SaTrungKim
+            | NapAm::KimBachKim
+            | NapAm::ThoaXuyenKim => Element::Kim,
+            NapAm::DaiLamMoc
+            | NapAm::DuongLieuMoc
+            | NapAm::TungBachMoc
+            | NapAm::BinhDiaMoc
+            | NapAm::TangDoMoc
+            | NapAm::ThachLuuMoc => Element::Moc,
+            NapAm::GianHaThuy
+            | NapAm::TuyenTrungThuy
+            | NapAm::TruongLuuThuy
+            | NapAm::ThienHaThuy
+            | NapAm::DaiKheThuy
+            | NapAm::DaiHaiThuy => Element::Thuy,
+            NapAm::LuTrungHoa
+            | NapAm::SonDauHoa
+            | NapAm::TichLichHoa
+            | NapAm::SonHaHoa
+            | NapAm::PhuDangHoa
+            | NapAm::ThienThuongHoa => Element::Hoa,
+            NapAm::LoBangTho
+            | NapAm::ThanhDauTho
+            | NapAm::OcThuongTho
+            | NapAm::BichThuongTho
+            | NapAm::DaiTrachTho
+            | NapAm::SaTrungTho => Element::Tho,
+        }
+    }
+}
+
+impl fmt::Display for NapAm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl CanChi {
+    /// Nạp âm of the pair, see `NapAm`
+    #[inline]
+    pub fn nap_am(&self) -> NapAm {
+        NapAm::from_can_chi(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canchi::{year_can_chi, EarthlyBranch, HeavenlyStem};
+
+    #[test]
+    fn nap_am_test() {
+        let can_chi = CanChi::new(HeavenlyStem::Giap, EarthlyBranch::Ty);
+        assert_eq!(NapAm::HaiTrungKim, can_chi.nap_am());
+        let can_chi = CanChi::new(HeavenlyStem::Quy, EarthlyBranch::Hoi);
+        assert_eq!(NapAm::DaiHaiThuy, can_chi.nap_am());
+
+        assert_eq!(NapAm::PhuDangHoa, year_can_chi(2024).nap_am());
+        assert_eq!(NapAm::PhuDangHoa, year_can_chi(2025).nap_am());
+        assert_eq!(NapAm::ThienHaThuy, year_can_chi(2026).nap_am());
+        assert_eq!(NapAm::ThachLuuMoc, year_can_chi(1980).nap_am());
+        assert_eq!(NapAm::DaiTrachTho, year_can_chi(1968).nap_am());
+        assert_eq!("Phú Đăng Hỏa", NapAm::PhuDangHoa.to_string());
+        assert_eq!("覆燈火", NapAm::PhuDangHoa.han());
+    }
+
+    #[test]
+    fn element_test() {
+        assert_eq!(Element::Hoa, NapAm::PhuDangHoa.element());
+        assert_eq!(Element::Tho, NapAm::DaiTrachTho.element());
+        for nap_am in NAP_AMS {
+            assert!(nap_am.name().ends_with(nap_am.element().name()));
+            assert!(nap_am.han().ends_with(nap_am.element().han()));
+        }
+        assert_eq!("Thủy", Element::Thuy.to_string());
+    }
+}
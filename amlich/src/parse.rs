@@ -0,0 +1,408 @@
+use std::str::FromStr;
+
+use chrono::{Datelike, FixedOffset, Utc};
+
+use super::{
+    canchi::{year_can_chi, CanChi, EARTHLY_BRANCHES, HEAVENLY_STEMS},
+    errors::ParseDateError,
+    julian_day::JulianDay,
+    solar2lunar,
+    time_zone::TimeZoneRule,
+    try_lunar2solar, LunarDate, SolarDate,
+};
+
+// yyyy-mm-dd, the year may be negative
+fn parse_ymd(s: &str) -> Option<(i32, u32, u32)> {
+    let (sign, rest) = match s.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, s),
+    };
+    let mut parts = rest.splitn(3, '-');
+    let year: i32 = parts.next()?.parse().ok()?;
+    let month = parts.next()?.parse().ok()?;
+    let day = parts.next()?.parse().ok()?;
+
+    Some((sign * year, month, day))
+}
+
+// dd/mm/yyyy, or dd/mm when the year may be left out
+fn parse_dmy(s: &str, year_optional: bool) -> Option<(Option<i32>, u32, u32)> {
+    let parts: Vec<&str> = s.split('/').collect();
+    let year = match parts.len() {
+        3 => Some(parts[2].parse().ok()?),
+        2 if year_optional => None,
+        _ => return None,
+    };
+
+    Some((year, parts[1].parse().ok()?, parts[0].parse().ok()?))
+}
+
+/// `yyyy-mm-dd` as printed by `SolarDate`, not checked against the calendar
+pub(crate) fn parse_iso_solar(s: &str) -> Option<SolarDate> {
+    let (year, month, day) = parse_ymd(s)?;
+    Some(SolarDate::new(year, month, day))
+}
+
+/// Whether the solar date exists, in the Julian calendar before 15 October 1582
+pub(crate) fn solar_date_exists(solar_date: SolarDate) -> bool {
+    JulianDay::from(solar_date).to_solar_date() == solar_date
+}
+
+/// `yyyy-mm-dd` with an optional `L`, as printed by `LunarDate`, not checked
+/// against the calendar
+#[cfg(feature = "serde")]
+pub(crate) fn parse_iso_lunar(s: &str) -> Option<LunarDate> {
+    let (date, is_leap) = match s.strip_suffix('L') {
+        Some(date) => (date, true),
+        None => (s, false),
+    };
+    let (year, month, day) = parse_ymd(date)?;
+    Some(LunarDate::new(year, month, day, is_leap))
+}
+
+// Words that may surround a date without changing it
+const FILLER_WORDS: [&str; 4] = ["âm", "lịch", "al", "ngày"];
+
+fn number_word(word: &str) -> Option<u32> {
+    let number = match word {
+        "một" => 1,
+        "hai" => 2,
+        "ba" => 3,
+        "bốn" | "tư" => 4,
+        "năm" => 5,
+        "sáu" => 6,
+        "bảy" | "bẩy" => 7,
+        "tám" => 8,
+        "chín" => 9,
+        "mười" => 10,
+        _ => return None,
+    };
+
+    Some(number)
+}
+
+struct Tokens<'a> {
+    words: Vec<&'a str>,
+    position: usize,
+}
+
+impl<'a> Tokens<'a> {
+    fn peek(&self) -> Option<&'a str> {
+        self.words.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<&'a str> {
+        let word = self.peek();
+        self.position += 1;
+        word
+    }
+
+    fn next_if(&mut self, expected: &str) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += 1;
+            return true;
+        }
+        false
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position >= self.words.len()
+    }
+}
+
+// rằm, mùng 5, mồng năm, 20
+fn parse_day(tokens: &mut Tokens) -> Option<u32> {
+    let word = tokens.next()?;
+    if word == "rằm" {
+        return Some(15);
+    }
+    if word == "mùng" || word == "mồng" {
+        let word = tokens.next()?;
+        return word.parse().ok().or_else(|| number_word(word));
+    }
+
+    word.parse().ok()
+}
+
+// Giêng, 2, hai, Một (the 11th month), mười một, Chạp
+fn parse_month(tokens: &mut Tokens) -> Option<u32> {
+    let word = tokens.next()?;
+    let month = match word {
+        "giêng" => 1,
+        "một" => 11,
+        "chạp" => 12,
+        "mười" if tokens.next_if("một") => 11,
+        "mười" if tokens.next_if("hai") => 12,
+        _ => word.parse().ok().or_else(|| number_word(word))?,
+    };
+
+    Some(month)
+}
+
+fn parse_can_chi(stem: &str, branch: &str) -> Option<CanChi> {
+    let stem = HEAVENLY_STEMS
+        .into_iter()
+        .find(|s| s.name().to_lowercase() == stem)?;
+    let branch = EARTHLY_BRANCHES
+        .into_iter()
+        .find(|b| b.name().to_lowercase() == branch)?;
+    // only stems and branches of the same parity pair up
+    if stem.index() % 2 != branch.index() % 2 {
+        return None;
+    }
+
+    Some(CanChi::new(stem, branch))
+}
+
+/// The year with the given Can Chi closest to the reference year
+fn year_of_can_chi(can_chi: CanChi, reference_year: i32) -> i32 {
+    let offset = can_chi.index() as i32 - year_can_chi(reference_year).index() as i32;
+    reference_year + (offset + 30).rem_euclid(60) - 30
+}
+
+// 2024, Giáp Thìn
+fn parse_year(tokens: &mut Tokens, reference_year: i32) -> Option<i32> {
+    let word = tokens.next()?;
+    if let Ok(year) = word.parse() {
+        return Some(year);
+    }
+    let can_chi = parse_can_chi(word, tokens.next()?)?;
+
+    Some(year_of_can_chi(can_chi, reference_year))
+}
+
+fn parse_numeric_lunar(tokens: &mut Tokens, reference_year: i32) -> Option<LunarDate> {
+    let word = tokens.next()?;
+    let (date, mut is_leap) = match word.strip_suffix('l') {
+        Some(date) => (date, true),
+        None => (word, false),
+    };
+    is_leap |= tokens.next_if("nhuận");
+    let (year, month, day) = match parse_ymd(date) {
+        Some((year, month, day)) => (year, month, day),
+        None => {
+            let (year, month, day) = parse_dmy(date, true)?;
+            (year.unwrap_or(reference_year), month, day)
+        }
+    };
+
+    Some(LunarDate::new(year, month, day, is_leap))
+}
+
+fn parse_text_lunar(tokens: &mut Tokens, reference_year: i32) -> Option<LunarDate> {
+    let day = parse_day(tokens)?;
+    if !tokens.next_if("tháng") {
+        return None;
+    }
+    let month = parse_month(tokens)?;
+    let is_leap = tokens.next_if("nhuận");
+    let year = if tokens.next_if("năm") {
+        parse_year(tokens, reference_year)?
+    } else {
+        reference_year
+    };
+
+    Some(LunarDate::new(year, month, day, is_leap))
+}
+
+/// Parse a lunar date and check that it exists in the calendar of the time zone.
+///
+/// Accepts `2006-07-20`, `2006-07-20L`, `20/07/2006`, `20/07/2006 nhuận` and
+/// Vietnamese text such as `rằm tháng Giêng năm Giáp Thìn` or `mùng 5 tháng 5 nhuận`.
+/// A date without year is in the reference year, a Can Chi year is the one
+/// closest to it. As in speech, `tháng Một` is the 11th month.
+pub fn parse_lunar_date(
+    s: &str,
+    reference_year: i32,
+    time_zone: impl TimeZoneRule,
+) -> Result<LunarDate, ParseDateError> {
+    let text = s.trim().to_lowercase().replace(',', " ");
+    let words: Vec<&str> = text
+        .split_whitespace()
+        .filter(|word| !FILLER_WORDS.contains(word))
+        .collect();
+    let is_numeric = words.first().is_some_and(|word| word.contains(['-', '/']));
+    let mut tokens = Tokens { words, position: 0 };
+
+    let lunar_date = if is_numeric {
+        parse_numeric_lunar(&mut tokens, reference_year)
+    } else {
+        parse_text_lunar(&mut tokens, reference_year)
+    };
+    let lunar_date = match lunar_date {
+        Some(lunar_date) if tokens.is_empty() => lunar_date,
+        _ => return Err(ParseDateError::InvalidFormat),
+    };
+    try_lunar2solar(lunar_date, time_zone)?;
+
+    Ok(lunar_date)
+}
+
+/// Parse `yyyy-mm-dd` or `dd/mm/yyyy` and check that the date exists,
+/// in the Julian calendar before 15 October 1582
+pub fn parse_solar_date(s: &str) -> Result<SolarDate, ParseDateError> {
+    let s = s.trim();
+    let solar_date = match parse_iso_solar(s) {
+        Some(solar_date) => solar_date,
+        None => match parse_dmy(s, false) {
+            Some((Some(year), month, day)) => SolarDate::new(year, month, day),
+            _ => return Err(ParseDateError::InvalidFormat),
+        },
+    };
+    if !solar_date_exists(solar_date) {
+        return Err(ParseDateError::NoSuchDate);
+    }
+
+    Ok(solar_date)
+}
+
+/// Lunar dates are checked in UTC+7, a date without year is in the current lunar year
+impl FromStr for LunarDate {
+    type Err = ParseDateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let now = Utc::now().with_timezone(&FixedOffset::east_opt(7 * 3600).unwrap());
+        let today = SolarDate::new(now.year(), now.month(), now.day());
+        parse_lunar_date(s, solar2lunar(today, 7).year, 7)
+    }
+}
+
+impl TryFrom<&str> for LunarDate {
+    type Error = ParseDateError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl FromStr for SolarDate {
+    type Err = ParseDateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_solar_date(s)
+    }
+}
+
+impl TryFrom<&str> for SolarDate {
+    type Error = ParseDateError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LunarDateError;
+
+    fn parse(s: &str) -> Result<String, ParseDateError> {
+        parse_lunar_date(s, 2025, 7).map(|date| date.to_string())
+    }
+
+    #[test]
+    fn numeric_lunar_test() {
+        assert_eq!(Ok("2006-07-20L".to_string()), parse("2006-07-20L"));
+        assert_eq!(Ok("2006-07-20".to_string()), parse(" 2006-7-20 "));
+        assert_eq!(Ok("2006-07-20L".to_string()), parse("20/07/2006 nhuận"));
+        assert_eq!(Ok("2006-07-20L".to_string()), parse("20/7/2006L"));
+        assert_eq!(Ok("2025-06-01L".to_string()), parse("01/06 nhuận"));
+        assert_eq!(Err(ParseDateError::InvalidFormat), parse("2006-07"));
+        assert_eq!(Err(ParseDateError::InvalidFormat), parse("20.07.2006"));
+    }
+
+    #[test]
+    fn text_lunar_test() {
+        assert_eq!(
+            Ok("2024-01-15".to_string()),
+            parse("rằm tháng Giêng năm Giáp Thìn")
+        );
+        assert_eq!(Ok("2025-06-05L".to_string()), parse("mùng 5 tháng 6 nhuận"));
+        assert_eq!(Ok("2025-05-05".to_string()), parse("Mồng năm tháng năm"));
+        assert_eq!(
+            Ok("2025-08-15".to_string()),
+            parse("ngày 15 tháng 8 âm lịch")
+        );
+        assert_eq!(
+            Ok("2023-11-01".to_string()),
+            parse("mùng một tháng Một năm 2023")
+        );
+        assert_eq!(
+            Ok("2023-12-23".to_string()),
+            parse("23 tháng Chạp năm Quý Mão")
+        );
+        assert_eq!(
+            Ok("2024-12-29".to_string()),
+            parse("29 tháng mười hai, năm 2024")
+        );
+        assert_eq!(
+            Ok("2020-04-08L".to_string()),
+            parse("8 tháng tư nhuận năm Canh Tý")
+        );
+        // the Can Chi year closest to the reference year
+        assert_eq!(
+            Ok("2054-01-01".to_string()),
+            parse("mùng 1 tháng 1 năm Giáp Tuất")
+        );
+
+        assert_eq!(Err(ParseDateError::InvalidFormat), parse("rằm tháng"));
+        assert_eq!(
+            Err(ParseDateError::InvalidFormat),
+            parse("rằm tháng 8 năm Giáp Sửu")
+        );
+        assert_eq!(
+            Err(ParseDateError::InvalidFormat),
+            parse("rằm tháng 8 năm 2024 xyz")
+        );
+    }
+
+    #[test]
+    fn validation_test() {
+        assert_eq!(
+            Err(ParseDateError::Lunar(LunarDateError::NoSuchLeapMonth {
+                year: 2025,
+                month: 5
+            })),
+            parse("mùng 5 tháng 5 nhuận")
+        );
+        assert_eq!(
+            Err(ParseDateError::Lunar(LunarDateError::DayOutOfRange {
+                day: 30,
+                days_in_month: 29
+            })),
+            parse("2024-01-30")
+        );
+        assert_eq!(
+            Err(ParseDateError::Lunar(LunarDateError::MonthOutOfRange(13))),
+            parse("2024-13-01")
+        );
+    }
+
+    #[test]
+    fn from_str_test() {
+        let lunar: LunarDate = "2006-07-20L".parse().unwrap();
+        assert_eq!(LunarDate::new(2006, 7, 20, true), lunar);
+        assert!(LunarDate::try_from("2006-06-20L").is_err());
+        assert!("rằm tháng 8".parse::<LunarDate>().is_ok());
+
+        assert_eq!(Ok(SolarDate::new(2024, 2, 10)), "2024-02-10".parse());
+        assert_eq!(
+            Ok(SolarDate::new(2024, 2, 29)),
+            SolarDate::try_from("29/02/2024")
+        );
+        assert_eq!(
+            Err(ParseDateError::NoSuchDate),
+            "2023-02-29".parse::<SolarDate>()
+        );
+        // leap day of the Julian calendar, and the days skipped by the reform
+        assert_eq!(Ok(SolarDate::new(1500, 2, 29)), "1500-02-29".parse());
+        assert_eq!(
+            Err(ParseDateError::NoSuchDate),
+            "1582-10-10".parse::<SolarDate>()
+        );
+        assert_eq!(
+            Err(ParseDateError::InvalidFormat),
+            "10/2024".parse::<SolarDate>()
+        );
+    }
+}
@@ -0,0 +1,457 @@
+//! Higher precision astronomical model used with the `precise` feature: the full
+//! Meeus new moon series with planetary arguments, the Earth's heliocentric longitude
+//! from a truncated VSOP87 with nutation and aberration, and the Espenak-Meeus
+//! polynomials for ΔT. Both models are always compiled so they can be compared.
+
+use std::f64::consts::PI;
+
+use super::moon::{phase_jde, MoonPhase};
+
+// Meeus lunation number of the new moon of 1900 January 1, the k = 0 of new_moon
+const LUNATIONS_FROM_1900: i64 = 1237;
+
+/// ΔT (TT - UT) in days for the given julian date, Espenak and Meeus (2006)
+pub fn delta_t(jd: f64) -> f64 {
+    let y = 2000.0 + (jd - 2451544.5) / 365.2425;
+    let seconds = if !(-500.0..2150.0).contains(&y) {
+        let u = (y - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u
+    } else if y < 500.0 {
+        let u = y / 100.0;
+        10583.6 - 1014.41 * u + 33.78311 * u.powi(2) - 5.952053 * u.powi(3) - 0.1798452 * u.powi(4)
+            + 0.022174192 * u.powi(5)
+            + 0.0090316521 * u.powi(6)
+    } else if y < 1600.0 {
+        let u = (y - 1000.0) / 100.0;
+        1574.2 - 556.01 * u + 71.23472 * u.powi(2) + 0.319781 * u.powi(3)
+            - 0.8503463 * u.powi(4)
+            - 0.005050998 * u.powi(5)
+            + 0.0083572073 * u.powi(6)
+    } else if y < 1700.0 {
+        let t = y - 1600.0;
+        120.0 - 0.9808 * t - 0.01532 * t.powi(2) + t.powi(3) / 7129.0
+    } else if y < 1800.0 {
+        let t = y - 1700.0;
+        8.83 + 0.1603 * t - 0.0059285 * t.powi(2) + 0.00013336 * t.powi(3) - t.powi(4) / 1174000.0
+    } else if y < 1860.0 {
+        let t = y - 1800.0;
+        13.72 - 0.332447 * t + 0.0068612 * t.powi(2) + 0.0041116 * t.powi(3)
+            - 0.00037436 * t.powi(4)
+            + 0.0000121272 * t.powi(5)
+            - 0.0000001699 * t.powi(6)
+            + 0.000000000875 * t.powi(7)
+    } else if y < 1900.0 {
+        let t = y - 1860.0;
+        7.62 + 0.5737 * t - 0.251754 * t.powi(2) + 0.01680668 * t.powi(3) - 0.0004473624 * t.powi(4)
+            + t.powi(5) / 233174.0
+    } else if y < 1920.0 {
+        let t = y - 1900.0;
+        -2.79 + 1.494119 * t - 0.0598939 * t.powi(2) + 0.0061966 * t.powi(3) - 0.000197 * t.powi(4)
+    } else if y < 1941.0 {
+        let t = y - 1920.0;
+        21.2 + 0.84493 * t - 0.0761 * t.powi(2) + 0.0020936 * t.powi(3)
+    } else if y < 1961.0 {
+        let t = y - 1950.0;
+        29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+    } else if y < 1986.0 {
+        let t = y - 1975.0;
+        45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+    } else if y < 2005.0 {
+        let t = y - 2000.0;
+        63.86 + 0.3345 * t - 0.060374 * t.powi(2)
+            + 0.0017275 * t.powi(3)
+            + 0.000651814 * t.powi(4)
+            + 0.00002373599 * t.powi(5)
+    } else if y < 2050.0 {
+        let t = y - 2000.0;
+        62.92 + 0.32217 * t + 0.005589 * t.powi(2)
+    } else {
+        let u = (y - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u - 0.5628 * (2150.0 - y)
+    };
+
+    seconds / 86400.0
+}
+
+#[inline]
+fn sin(degrees: f64) -> f64 {
+    (degrees * PI / 180.0).sin()
+}
+
+// Periodic terms of a new moon left out of moon::phase_jde, with the planetary arguments,
+// k = 0 is the first new moon of 2000. Astronomical Algorithms (Meeus), chapter 49.
+fn new_moon_additional_terms(k: f64) -> f64 {
+    let t = k / 1236.85;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let t4 = t3 * t;
+    let m = 2.5534 + 29.1053567 * k - 0.0000014 * t2 - 0.00000011 * t3;
+    let mp = 201.5643 + 385.81693528 * k + 0.0107582 * t2 + 0.00001238 * t3 - 0.000000058 * t4;
+    let f = 160.7108 + 390.67050284 * k - 0.0016118 * t2 - 0.00000227 * t3 + 0.000000011 * t4;
+
+    let periodic = -0.00007 * sin(mp + 2.0 * m)
+        + 0.00004 * sin(2.0 * mp - 2.0 * f)
+        + 0.00004 * sin(3.0 * m)
+        + 0.00003 * sin(mp + m - 2.0 * f)
+        + 0.00003 * sin(2.0 * mp + 2.0 * f)
+        - 0.00003 * sin(mp + m + 2.0 * f)
+        + 0.00003 * sin(mp - m + 2.0 * f)
+        - 0.00002 * sin(mp - m - 2.0 * f)
+        - 0.00002 * sin(3.0 * mp + m)
+        + 0.00002 * sin(4.0 * mp);
+
+    let planetary = [
+        (0.000325, 299.77 + 0.107408 * k - 0.009173 * t2),
+        (0.000165, 251.88 + 0.016321 * k),
+        (0.000164, 251.83 + 26.651886 * k),
+        (0.000126, 349.42 + 36.412478 * k),
+        (0.00011, 84.66 + 18.206239 * k),
+        (0.000062, 141.74 + 53.303771 * k),
+        (0.00006, 207.14 + 2.453732 * k),
+        (0.000056, 154.84 + 7.30686 * k),
+        (0.000047, 34.52 + 27.261239 * k),
+        (0.000042, 207.19 + 0.121824 * k),
+        (0.00004, 291.34 + 1.844379 * k),
+        (0.000037, 161.72 + 24.198154 * k),
+        (0.000035, 239.56 + 25.513099 * k),
+        (0.000023, 331.55 + 3.592518 * k),
+    ]
+    .iter()
+    .map(|(amplitude, argument)| amplitude * sin(*argument))
+    .sum::<f64>();
+
+    periodic + planetary
+}
+
+/// Julian date (UT) of the k-th new moon counted from 1900 January 1, same as fns::new_moon
+pub fn new_moon(k: i64) -> f64 {
+    let k = (k - LUNATIONS_FROM_1900) as f64;
+    let jde = phase_jde(k, MoonPhase::NewMoon) + new_moon_additional_terms(k);
+    jde - delta_t(jde)
+}
+
+// (amplitude 1e-8 rad, phase, frequency) of the Earth's heliocentric longitude,
+// VSOP87 series L0 to L5 truncated as in Astronomical Algorithms, appendix III
+const L0: [(f64, f64, f64); 64] = [
+    (175347046.0, 0.0, 0.0),
+    (3341656.0, 4.6692568, 6283.07585),
+    (34894.0, 4.6261, 12566.1517),
+    (3497.0, 2.7441, 5753.3849),
+    (3418.0, 2.8289, 3.5231),
+    (3136.0, 3.6277, 77713.7715),
+    (2676.0, 4.4181, 7860.4194),
+    (2343.0, 6.1352, 3930.2097),
+    (1324.0, 0.7425, 11506.7698),
+    (1273.0, 2.0371, 529.691),
+    (1199.0, 1.1096, 1577.3435),
+    (990.0, 5.233, 5884.927),
+    (902.0, 2.045, 26.298),
+    (857.0, 3.508, 398.149),
+    (780.0, 1.179, 5223.694),
+    (753.0, 2.533, 5507.553),
+    (505.0, 4.583, 18849.228),
+    (492.0, 4.205, 775.523),
+    (357.0, 2.92, 0.067),
+    (317.0, 5.849, 11790.629),
+    (284.0, 1.899, 796.298),
+    (271.0, 0.315, 10977.079),
+    (243.0, 0.345, 5486.778),
+    (206.0, 4.806, 2544.314),
+    (205.0, 1.869, 5573.143),
+    (202.0, 2.458, 6069.777),
+    (156.0, 0.833, 213.299),
+    (132.0, 3.411, 2942.463),
+    (126.0, 1.083, 20.775),
+    (115.0, 0.645, 0.98),
+    (103.0, 0.636, 4694.003),
+    (102.0, 0.976, 15720.839),
+    (102.0, 4.267, 7.114),
+    (99.0, 6.21, 2146.17),
+    (98.0, 0.68, 155.42),
+    (86.0, 5.98, 161000.69),
+    (85.0, 1.3, 6275.96),
+    (85.0, 3.67, 71430.7),
+    (80.0, 1.81, 17260.15),
+    (79.0, 3.04, 12036.46),
+    (75.0, 1.76, 5088.63),
+    (74.0, 3.5, 3154.69),
+    (74.0, 4.68, 801.82),
+    (70.0, 0.83, 9437.76),
+    (62.0, 3.98, 8827.39),
+    (61.0, 1.82, 7084.9),
+    (57.0, 2.78, 6286.6),
+    (56.0, 4.39, 14143.5),
+    (56.0, 3.47, 6279.55),
+    (52.0, 0.19, 12139.55),
+    (52.0, 1.33, 1748.02),
+    (51.0, 0.28, 5856.48),
+    (49.0, 0.49, 1194.45),
+    (41.0, 5.37, 8429.24),
+    (41.0, 2.4, 19651.05),
+    (39.0, 6.17, 10447.39),
+    (37.0, 6.04, 10213.29),
+    (37.0, 2.57, 1059.38),
+    (36.0, 1.71, 2352.87),
+    (36.0, 1.78, 6812.77),
+    (33.0, 0.59, 17789.85),
+    (30.0, 0.44, 83996.85),
+    (30.0, 2.74, 1349.87),
+    (25.0, 3.16, 4690.48),
+];
+
+const L1: [(f64, f64, f64); 34] = [
+    (628331966747.0, 0.0, 0.0),
+    (206059.0, 2.678235, 6283.07585),
+    (4303.0, 2.6351, 12566.1517),
+    (425.0, 1.59, 3.523),
+    (119.0, 5.796, 26.298),
+    (109.0, 2.966, 1577.344),
+    (93.0, 2.59, 18849.23),
+    (72.0, 1.14, 529.69),
+    (68.0, 1.87, 398.15),
+    (67.0, 4.41, 5507.55),
+    (59.0, 2.89, 5223.69),
+    (56.0, 2.17, 155.42),
+    (45.0, 0.4, 796.3),
+    (36.0, 0.47, 775.52),
+    (29.0, 2.65, 7.11),
+    (21.0, 5.34, 0.98),
+    (19.0, 1.85, 5486.78),
+    (19.0, 4.97, 213.3),
+    (17.0, 2.99, 6275.96),
+    (16.0, 0.03, 2544.31),
+    (16.0, 1.43, 2146.17),
+    (15.0, 1.21, 10977.08),
+    (12.0, 2.83, 1748.02),
+    (12.0, 3.26, 5088.63),
+    (12.0, 5.27, 1194.45),
+    (12.0, 2.08, 4694.0),
+    (11.0, 0.77, 553.57),
+    (10.0, 1.3, 6286.6),
+    (10.0, 4.24, 1349.87),
+    (9.0, 2.7, 242.73),
+    (9.0, 5.64, 951.72),
+    (8.0, 5.3, 2352.87),
+    (6.0, 2.65, 9437.76),
+    (6.0, 4.67, 4690.48),
+];
+
+const L2: [(f64, f64, f64); 20] = [
+    (52919.0, 0.0, 0.0),
+    (8720.0, 1.0721, 6283.0758),
+    (309.0, 0.867, 12566.152),
+    (27.0, 0.05, 3.52),
+    (16.0, 5.19, 26.3),
+    (16.0, 3.68, 155.42),
+    (10.0, 0.76, 18849.23),
+    (9.0, 2.06, 77713.77),
+    (7.0, 0.83, 775.52),
+    (5.0, 4.66, 1577.34),
+    (4.0, 1.03, 7.11),
+    (4.0, 3.44, 5573.14),
+    (3.0, 5.14, 796.3),
+    (3.0, 6.05, 5507.55),
+    (3.0, 1.19, 242.73),
+    (3.0, 6.12, 529.69),
+    (3.0, 0.31, 398.15),
+    (3.0, 2.28, 553.57),
+    (2.0, 4.38, 5223.69),
+    (2.0, 3.75, 0.98),
+];
+
+const L3: [(f64, f64, f64); 7] = [
+    (289.0, 5.844, 6283.076),
+    (35.0, 0.0, 0.0),
+    (17.0, 5.49, 12566.15),
+    (3.0, 5.2, 155.42),
+    (1.0, 4.72, 3.52),
+    (1.0, 5.3, 18849.23),
+    (1.0, 5.97, 242.73),
+];
+
+// phases of the published tables, not approximations of PI
+#[allow(clippy::approx_constant)]
+const L4: [(f64, f64, f64); 3] = [
+    (114.0, 3.142, 0.0),
+    (8.0, 4.13, 6283.08),
+    (1.0, 3.84, 12566.15),
+];
+
+#[allow(clippy::approx_constant)]
+const L5: [(f64, f64, f64); 1] = [(1.0, 3.14, 0.0)];
+
+fn series(terms: &[(f64, f64, f64)], tau: f64) -> f64 {
+    terms.iter().map(|(a, b, c)| a * (b + c * tau).cos()).sum()
+}
+
+/// Apparent geocentric longitude of the sun in radians at the given julian date (UT),
+/// same as fns::sun_longitude
+pub fn sun_longitude(jdn: f64) -> f64 {
+    let jde = jdn + delta_t(jdn);
+    let tau = (jde - 2451545.0) / 365250.0; // Julian millennia from J2000.0
+    let t = tau * 10.0;
+    let dr = PI / 180.0;
+
+    let l = [&L0[..], &L1, &L2, &L3, &L4, &L5]
+        .iter()
+        .rev()
+        .fold(0.0, |acc, terms| acc * tau + series(terms, tau))
+        / 1e8;
+
+    // geocentric longitude with the FK5 correction, degrees
+    let mut longitude = l / dr + 180.0 - 0.09033 / 3600.0;
+
+    // nutation in longitude and aberration
+    let omega = 125.04452 - 1934.136261 * t;
+    let sun_mean = 280.4665 + 36000.7698 * t;
+    let moon_mean = 218.3165 + 481267.8813 * t;
+    let nutation = -17.2 * (omega * dr).sin()
+        - 1.32 * (2.0 * sun_mean * dr).sin()
+        - 0.23 * (2.0 * moon_mean * dr).sin()
+        + 0.21 * (2.0 * omega * dr).sin();
+    longitude += (nutation - 20.4898) / 3600.0;
+
+    (longitude * dr).rem_euclid(2.0 * PI)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fns::{jd_from_date, jd_to_date, jd_to_datetime, truncated_new_moon};
+
+    #[test]
+    fn delta_t_test() {
+        let seconds = |y, m, d| delta_t(jd_from_date(d, m, y) as f64) * 86400.0;
+        assert!((seconds(1900, 1, 1) + 2.7).abs() < 1.0);
+        assert!((seconds(1950, 1, 1) - 29.1).abs() < 1.0);
+        assert!((seconds(2000, 1, 1) - 63.8).abs() < 1.0);
+        assert!((seconds(2024, 1, 1) - 73.9).abs() < 1.0);
+
+        // before 500 with the year from the epoch, the branches meet at -500 and 500
+        let seconds = |y: f64| delta_t(2451544.5 + (y - 2000.0) * 365.2425) * 86400.0;
+        assert!((seconds(0.0) - 10583.6).abs() < 1.0);
+        assert!((seconds(-500.0) - seconds(-500.001)).abs() < 1.0);
+        assert!((seconds(500.0) - seconds(499.999)).abs() < 1.0);
+    }
+
+    #[test]
+    fn sun_longitude_test() {
+        // Astronomical Algorithms example 25.b: 1992 October 13.0 TD, 199°54'21.818"
+        let jde = 2448908.5;
+        let longitude = sun_longitude(jde - delta_t(jde)).to_degrees();
+        assert!((longitude - 199.906061).abs() < 0.0005, "{}", longitude);
+    }
+
+    #[test]
+    fn new_moon_test() {
+        // Astronomical Algorithms example 49.a: 1977 February 18, 3h37m42s TD
+        let jd = new_moon(-283 + LUNATIONS_FROM_1900) + delta_t(2443192.65);
+        assert!((jd - 2443192.65118).abs() < 0.0001, "{}", jd);
+
+        let k = ((2460321.0 - 2415021.0) / 29.530588853_f64).round() as i64;
+        let time = jd_to_datetime(new_moon(k), &0);
+        assert_eq!(
+            "2024-01-11 11:57",
+            time.format("%Y-%m-%d %H:%M").to_string()
+        );
+    }
+
+    // New moons in UT rounded to the minute, from the NASA Six Millennium Catalog of
+    // Phases of the Moon (Espenak)
+    const PUBLISHED_NEW_MOONS: [(i64, i64, i64, i64, i64); 8] = [
+        (1999, 8, 11, 11, 8),
+        (2000, 1, 6, 18, 14),
+        (2001, 1, 24, 13, 7),
+        (2009, 7, 22, 2, 35),
+        (2017, 8, 21, 18, 30),
+        (2023, 1, 21, 20, 53),
+        (2024, 2, 9, 22, 59),
+        (2024, 4, 8, 18, 21),
+    ];
+
+    // Greatest eclipse in TD, rounded to the minute, of central solar eclipses from the
+    // Five Millennium Canon of Solar Eclipses (Espenak and Meeus), which spans the ends
+    // of 1800-2200 where the phase table above has no entries. A central eclipse is
+    // always within an hour of the conjunction in longitude.
+    const CENTRAL_SOLAR_ECLIPSES: [(i64, i64, i64, i64, i64); 9] = [
+        (1806, 6, 16, 16, 24),
+        (1842, 7, 8, 7, 6),
+        (1851, 7, 28, 14, 33),
+        (1860, 7, 18, 14, 25),
+        (1869, 8, 7, 22, 1),
+        (1878, 7, 29, 21, 47),
+        (1900, 5, 28, 14, 53),
+        (1919, 5, 29, 13, 8),
+        (2186, 7, 16, 15, 28),
+    ];
+
+    fn lunation(jd: f64) -> i64 {
+        ((jd - 2415021.077) / 29.530588853).round() as i64
+    }
+
+    // local day number in Vietnam
+    fn day(jd: f64) -> i64 {
+        (jd + 0.5 + 7.0 / 24.0).floor() as i64
+    }
+
+    #[test]
+    fn published_new_moons_test() {
+        for (year, month, date, hour, minute) in PUBLISHED_NEW_MOONS {
+            let jd =
+                jd_from_date(date, month, year) as f64 - 0.5 + (hour * 60 + minute) as f64 / 1440.0;
+            let k = lunation(jd);
+
+            let precise = new_moon(k);
+            assert!((precise - jd).abs() * 1440.0 < 1.0, "{year}-{month}-{date}");
+            assert_eq!(day(jd), day(precise));
+
+            let truncated = truncated_new_moon(k);
+            assert!(
+                (truncated - jd).abs() * 1440.0 < 3.0,
+                "{year}-{month}-{date}"
+            );
+            assert_eq!(day(jd), day(truncated));
+        }
+    }
+
+    #[test]
+    fn central_solar_eclipses_test() {
+        for (year, month, date, hour, minute) in CENTRAL_SOLAR_ECLIPSES {
+            let jde =
+                jd_from_date(date, month, year) as f64 - 0.5 + (hour * 60 + minute) as f64 / 1440.0;
+            let k = lunation(jde);
+
+            for new_moon in [new_moon(k), truncated_new_moon(k)] {
+                let minutes = (new_moon + delta_t(jde) - jde).abs() * 1440.0;
+                assert!(minutes < 60.0, "{year}-{month}-{date}: {minutes}");
+            }
+        }
+    }
+
+    #[test]
+    fn default_model_days_test() {
+        // new moons from 1800 to 2200 the default model puts on another day in Vietnam
+        // than this one, all within a few minutes of midnight
+        let first = lunation(jd_from_date(1, 1, 1800) as f64);
+        let last = lunation(jd_from_date(31, 12, 2200) as f64);
+        let days: Vec<String> = (first..=last)
+            .filter(|k| day(new_moon(*k)) != day(truncated_new_moon(*k)))
+            .map(|k| jd_to_date(day(new_moon(k))).to_string())
+            .collect();
+
+        assert_eq!(
+            vec![
+                "1944-06-20",
+                "1967-07-07",
+                "2072-12-09",
+                "2077-11-15",
+                "2130-05-07",
+                "2150-05-26",
+                "2159-05-17",
+                "2175-01-22",
+                "2199-01-26"
+            ],
+            days
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{
+    errors::ParseDateError,
+    parse::{parse_iso_lunar, parse_iso_solar, solar_date_exists},
+    try_lunar2solar, LunarDate, SolarDate,
+};
+
+// The structured form, field names as in the date types
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "SolarDate")]
+struct SolarFields {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "LunarDate")]
+struct LunarFields {
+    year: i32,
+    month: u32,
+    day: u32,
+    #[serde(default)]
+    is_leap: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Repr<T> {
+    Compact(String),
+    Structured(T),
+}
+
+/// `2024-02-10`
+impl Serialize for SolarDate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+/// `2006-07-20L` for a day of a leap month
+impl Serialize for LunarDate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+/// Either the string or the structured form, of a date that exists
+impl<'de> Deserialize<'de> for SolarDate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let solar_date = match Repr::<SolarFields>::deserialize(deserializer)? {
+            Repr::Compact(s) => parse_iso_solar(&s).ok_or_else(|| {
+                de::Error::invalid_value(de::Unexpected::Str(&s), &"a date as yyyy-mm-dd")
+            })?,
+            Repr::Structured(fields) => SolarDate::new(fields.year, fields.month, fields.day),
+        };
+        if !solar_date_exists(solar_date) {
+            return Err(de::Error::custom(format_args!(
+                "{solar_date}: {}",
+                ParseDateError::NoSuchDate
+            )));
+        }
+
+        Ok(solar_date)
+    }
+}
+
+/// Either the string or the structured form, of a date that exists in UTC+7
+impl<'de> Deserialize<'de> for LunarDate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let lunar_date = match Repr::<LunarFields>::deserialize(deserializer)? {
+            Repr::Compact(s) => parse_iso_lunar(&s).ok_or_else(|| {
+                de::Error::invalid_value(
+                    de::Unexpected::Str(&s),
+                    &"a lunar date as yyyy-mm-dd, with a trailing L for a leap month",
+                )
+            })?,
+            Repr::Structured(fields) => {
+                LunarDate::new(fields.year, fields.month, fields.day, fields.is_leap)
+            }
+        };
+        try_lunar2solar(lunar_date, 7)
+            .map_err(|error| de::Error::custom(format_args!("{lunar_date}: {error}")))?;
+
+        Ok(lunar_date)
+    }
+}
+
+/// Structured form `{"year": 2006, "month": 7, "day": 20, "is_leap": true}`,
+/// to use with `#[serde(with = "amlich::structured")]`
+pub mod structured {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{LunarDate, LunarFields, SolarDate, SolarFields};
+
+    pub trait Structured: Sized {
+        #[doc(hidden)]
+        fn serialize_fields<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+    }
+
+    impl Structured for SolarDate {
+        fn serialize_fields<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            SolarFields {
+                year: self.year,
+                month: self.month,
+                day: self.day,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl Structured for LunarDate {
+        fn serialize_fields<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            LunarFields {
+                year: self.year,
+                month: self.month,
+                day: self.day,
+                is_leap: self.is_leap,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    pub fn serialize<T, S>(date: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Structured,
+        S: Serializer,
+    {
+        date.serialize_fields(serializer)
+    }
+
+    /// Accepts the string form as well
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Structured + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[test]
+    fn compact_test() {
+        let lunar = LunarDate::new(2006, 7, 20, true);
+        let json = serde_json::to_string(&lunar).unwrap();
+        assert_eq!("\"2006-07-20L\"", json);
+        assert_eq!(lunar, serde_json::from_str(&json).unwrap());
+
+        let solar = SolarDate::new(2006, 9, 11);
+        let json = serde_json::to_string(&solar).unwrap();
+        assert_eq!("\"2006-09-11\"", json);
+        assert_eq!(solar, serde_json::from_str(&json).unwrap());
+
+        assert!(serde_json::from_str::<SolarDate>("\"2006/09/11\"").is_err());
+        assert!(serde_json::from_str::<LunarDate>("\"2006-07\"").is_err());
+    }
+
+    #[test]
+    fn nonexistent_date_test() {
+        let error = serde_json::from_str::<SolarDate>("\"2024-02-30\"").unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("2024-02-30: The date does not exist"));
+        assert!(serde_json::from_str::<SolarDate>(r#"{"year":2023,"month":2,"day":29}"#).is_err());
+        // 1500 is a leap year in the Julian calendar
+        assert!(serde_json::from_str::<SolarDate>("\"1500-02-29\"").is_ok());
+
+        // 2024 has no leap month, the 12th month of 2024 has 29 days
+        assert!(serde_json::from_str::<LunarDate>("\"2024-06-01L\"").is_err());
+        assert!(serde_json::from_str::<LunarDate>("\"2024-12-30\"").is_err());
+        assert!(serde_json::from_str::<LunarDate>(r#"{"year":2024,"month":13,"day":1}"#).is_err());
+        assert!(serde_json::from_str::<LunarDate>("\"2025-06-01L\"").is_ok());
+    }
+
+    #[test]
+    fn structured_test() {
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        struct Event {
+            #[serde(with = "structured")]
+            lunar: LunarDate,
+            #[serde(with = "structured")]
+            solar: SolarDate,
+        }
+
+        let event = Event {
+            lunar: LunarDate::new(2006, 7, 20, true),
+            solar: SolarDate::new(2006, 9, 11),
+        };
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(
+            r#"{"lunar":{"year":2006,"month":7,"day":20,"is_leap":true},"solar":{"year":2006,"month":9,"day":11}}"#,
+            json
+        );
+        assert_eq!(event, serde_json::from_str(&json).unwrap());
+
+        // both forms are accepted everywhere, is_leap defaults to false
+        let lunar: LunarDate = serde_json::from_str(r#"{"year":2024,"month":1,"day":1}"#).unwrap();
+        assert_eq!(LunarDate::new(2024, 1, 1, false), lunar);
+        let event: Event =
+            serde_json::from_str(r#"{"lunar":"2006-07-20L","solar":"2006-09-11"}"#).unwrap();
+        assert_eq!(LunarDate::new(2006, 7, 20, true), event.lunar);
+    }
+
+    #[test]
+    fn map_key_test() {
+        let mut days = BTreeMap::new();
+        days.insert(LunarDate::new(2006, 8, 1, false), "month 8");
+        days.insert(LunarDate::new(2006, 7, 1, true), "leap month 7");
+        days.insert(LunarDate::new(2006, 7, 1, false), "month 7");
+        let json = serde_json::to_string(&days).unwrap();
+        assert_eq!(
+            r#"{"2006-07-01":"month 7","2006-07-01L":"leap month 7","2006-08-01":"month 8"}"#,
+            json
+        );
+        assert_eq!(
+            days,
+            serde_json::from_str::<BTreeMap<LunarDate, &str>>(&json).unwrap()
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use std::f64::consts::PI;
+use std::fmt;
+
+use chrono::{DateTime, FixedOffset};
+
+use super::{
+    fns::{jd_from_date, jd_to_datetime, sun_longitude},
+    time_zone::TimeZoneRule,
+    SolarDate,
+};
+
+/// The 24 solar terms (tiết khí), starting with Lập Xuân at 315°
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SolarTerm {
+    LapXuan = 0,
+    VuThuy = 1,
+    KinhTrap = 2,
+    XuanPhan = 3,
+    ThanhMinh = 4,
+    CocVu = 5,
+    LapHa = 6,
+    TieuMan = 7,
+    MangChung = 8,
+    HaChi = 9,
+    TieuThu = 10,
+    DaiThu = 11,
+    LapThu = 12,
+    XuThu = 13,
+    BachLo = 14,
+    ThuPhan = 15,
+    HanLo = 16,
+    SuongGiang = 17,
+    LapDong = 18,
+    TieuTuyet = 19,
+    DaiTuyet = 20,
+    DongChi = 21,
+    TieuHan = 22,
+    DaiHan = 23,
+}
+
+pub const SOLAR_TERMS: [SolarTerm; 24] = [
+    SolarTerm::LapXuan,
+    SolarTerm::VuThuy,
+    SolarTerm::KinhTrap,
+    SolarTerm::XuanPhan,
+    SolarTerm::ThanhMinh,
+    SolarTerm::CocVu,
+    SolarTerm::LapHa,
+    SolarTerm::TieuMan,
+    SolarTerm::MangChung,
+    SolarTerm::HaChi,
+    SolarTerm::TieuThu,
+    SolarTerm::DaiThu,
+    SolarTerm::LapThu,
+    SolarTerm::XuThu,
+    SolarTerm::BachLo,
+    SolarTerm::ThuPhan,
+    SolarTerm::HanLo,
+    SolarTerm::SuongGiang,
+    SolarTerm::LapDong,
+    SolarTerm::TieuTuyet,
+    SolarTerm::DaiTuyet,
+    SolarTerm::DongChi,
+    SolarTerm::TieuHan,
+    SolarTerm::DaiHan,
+];
+
+impl SolarTerm {
+    /// Solar term which starts at the given ecliptic longitude segment,
+    /// e.g. any longitude in [315, 330) is Lập Xuân
+    pub fn from_longitude(degrees: f64) -> Self {
+        let segment = (degrees / 15.0).floor() as i64;
+        SOLAR_TERMS[(segment - 21).rem_euclid(24) as usize]
+    }
+
+    #[inline]
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    /// Ecliptic longitude of the sun in degrees when this term starts
+    pub fn longitude(&self) -> u32 {
+        (315 + 15 * self.index() as u32) % 360
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SolarTerm::LapXuan => "Lập Xuân",
+            SolarTerm::VuThuy => "Vũ Thủy",
+            SolarTerm::KinhTrap => "Kinh Trập",
+            SolarTerm::XuanPhan => "Xuân Phân",
+            SolarTerm::ThanhMinh => "Thanh Minh",
+            SolarTerm::CocVu => "Cốc Vũ",
+            SolarTerm::LapHa => "Lập Hạ",
+            SolarTerm::TieuMan => "Tiểu Mãn",
+            SolarTerm::MangChung => "Mang Chủng",
+            SolarTerm::HaChi => "Hạ Chí",
+            SolarTerm::TieuThu => "Tiểu Thử",
+            SolarTerm::DaiThu => "Đại Thử",
+            SolarTerm::LapThu => "Lập Thu",
+            SolarTerm::XuThu => "Xử Thử",
+            SolarTerm::BachLo => "Bạch Lộ",
+            SolarTerm::ThuPhan => "Thu Phân",
+            SolarTerm::HanLo => "Hàn Lộ",
+            SolarTerm::SuongGiang => "Sương Giáng",
+            SolarTerm::LapDong => "Lập Đông",
+            SolarTerm::TieuTuyet => "Tiểu Tuyết",
+            SolarTerm::DaiTuyet => "Đại Tuyết",
+            SolarTerm::DongChi => "Đông Chí",
+            SolarTerm::TieuHan => "Tiểu Hàn",
+            SolarTerm::DaiHan => "Đại Hàn",
+        }
+    }
+}
+
+impl fmt::Display for SolarTerm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[inline]
+fn sun_longitude_degrees(jd: f64) -> f64 {
+    sun_longitude(jd) * 180.0 / PI
+}
+
+// Instant (julian date, UT) at the end of the local day with the given day number
+#[inline]
+fn end_of_local_day(day_number: i64, time_zone: &dyn TimeZoneRule) -> f64 {
+    day_number as f64 + 0.5 - time_zone.offset_minutes(day_number) as f64 / 1440.0
+}
+
+// Bisect the instant in (start, end] where the sun reaches the given longitude
+fn find_longitude(target: f64, mut start: f64, mut end: f64) -> f64 {
+    // about 0.1 second
+    while end - start > 0.000001 {
+        let middle = (start + end) / 2.0;
+        let diff = (sun_longitude_degrees(middle) - target + 540.0).rem_euclid(360.0) - 180.0;
+        if diff < 0.0 {
+            start = middle;
+        } else {
+            end = middle;
+        }
+    }
+
+    end
+}
+
+/// All 24 solar terms starting in the given solar year, in chronological order
+/// (from Tiểu Hàn in January to Đông Chí in December), with local start time
+pub fn solar_terms_of_year(
+    year: i32,
+    time_zone: impl TimeZoneRule,
+) -> Vec<(SolarTerm, DateTime<FixedOffset>)> {
+    let time_zone = &time_zone as &dyn TimeZoneRule;
+    let mut terms = vec![];
+    let first_day = jd_from_date(1, 1, year as i64);
+    let last_day = jd_from_date(31, 12, year as i64);
+
+    let mut previous_end = end_of_local_day(first_day - 1, time_zone);
+    let mut previous_term = SolarTerm::from_longitude(sun_longitude_degrees(previous_end));
+
+    for day_number in first_day..=last_day {
+        let end = end_of_local_day(day_number, time_zone);
+        let term = SolarTerm::from_longitude(sun_longitude_degrees(end));
+        if term != previous_term {
+            let jd = find_longitude(term.longitude() as f64, previous_end, end);
+            terms.push((term, jd_to_datetime(jd, time_zone)));
+        }
+        previous_end = end;
+        previous_term = term;
+    }
+
+    terms
+}
+
+/// Solar term the given day belongs to. The day on which a term starts belongs to that term.
+pub fn solar_term_of_day(solar_date: SolarDate, time_zone: impl TimeZoneRule) -> SolarTerm {
+    let time_zone = &time_zone as &dyn TimeZoneRule;
+    let day_number = jd_from_date(
+        solar_date.day as i64,
+        solar_date.month as i64,
+        solar_date.year as i64,
+    );
+
+    SolarTerm::from_longitude(sun_longitude_degrees(end_of_local_day(
+        day_number, time_zone,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_longitude_test() {
+        assert_eq!(SolarTerm::LapXuan, SolarTerm::from_longitude(315.0));
+        assert_eq!(SolarTerm::DaiHan, SolarTerm::from_longitude(314.9));
+        assert_eq!(SolarTerm::XuanPhan, SolarTerm::from_longitude(0.0));
+        assert_eq!(SolarTerm::KinhTrap, SolarTerm::from_longitude(359.9));
+        assert_eq!(SolarTerm::DongChi, SolarTerm::from_longitude(270.0));
+        for term in SOLAR_TERMS {
+            assert_eq!(term, SolarTerm::from_longitude(term.longitude() as f64));
+        }
+    }
+
+    fn assert_close(expected: &str, actual: DateTime<FixedOffset>) {
+        let expected = DateTime::parse_from_rfc3339(expected).unwrap();
+        // The series used is accurate to about a quarter of an hour
+        assert!(
+            (actual - expected).num_minutes().abs() <= 30,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn solar_terms_of_year_test() {
+        let terms = solar_terms_of_year(2024, 7);
+        assert_eq!(24, terms.len());
+        for (i, (term, _)) in terms.iter().enumerate() {
+            assert_eq!(SOLAR_TERMS[(i + 22) % 24], *term);
+        }
+
+        assert_eq!(SolarTerm::LapXuan, terms[2].0);
+        assert_close("2024-02-04T15:27:00+07:00", terms[2].1);
+        assert_eq!(SolarTerm::XuanPhan, terms[5].0);
+        assert_close("2024-03-20T10:06:00+07:00", terms[5].1);
+        assert_eq!(SolarTerm::HaChi, terms[11].0);
+        assert_close("2024-06-21T03:51:00+07:00", terms[11].1);
+        assert_eq!(SolarTerm::DongChi, terms[23].0);
+        assert_close("2024-12-21T16:20:00+07:00", terms[23].1);
+    }
+
+    #[test]
+    fn solar_term_of_day_test() {
+        assert_eq!(
+            SolarTerm::DaiHan,
+            solar_term_of_day(SolarDate::new(2024, 2, 3), 7)
+        );
+        assert_eq!(
+            SolarTerm::LapXuan,
+            solar_term_of_day(SolarDate::new(2024, 2, 4), 7)
+        );
+        assert_eq!(
+            SolarTerm::LapXuan,
+            solar_term_of_day(SolarDate::new(2024, 2, 18), 7)
+        );
+        assert_eq!(
+            SolarTerm::VuThuy,
+            solar_term_of_day(SolarDate::new(2024, 2, 19), 7)
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use super::{
+    errors::LunarDateError,
+    fns::{jd_from_date, jd_to_date},
+    LunarDate, SolarDate,
+};
+
+/// Offset the table was computed for, UTC+7
+pub const TABLE_OFFSET_MINUTES: i64 = 7 * 60;
+pub const TABLE_FIRST_YEAR: i32 = 1800;
+pub const TABLE_LAST_YEAR: i32 = 2200;
+
+// One entry per lunar year from TABLE_FIRST_YEAR to TABLE_LAST_YEAR, computed with
+// the astronomical model for UTC+7:
+// bits 0-12: 1 if the month has 30 days, months in chronological order
+// bits 13-16: leap month, 0 if none
+// bits 17-23: days from solar 1 January to the first day of the lunar year
+const LUNAR_YEARS: [u32; 401] = [
+    0x3092b5, 0x560ad6, 0x4206d4, 0x2c4da9, 0x520ec9, 0x3cee92, 0x600693, 0x4a0527, 0x34aa57,
+    0x58095b, 0x440b5a, 0x3076d4, 0x560754, 0x3e0749, 0x285693, 0x4e0a93, 0x38d52b, 0x5c052d,
+    0x46096d, 0x328b6a, 0x580daa, 0x420ba4, 0x2c7b49, 0x520d49, 0x3cfa95, 0x600a96, 0x4a052e,
+    0x34aaad, 0x5a0ad5, 0x440daa, 0x309da4, 0x560ea4, 0x413d4a, 0x640d4a, 0x4e0a96, 0x38d536,
+    0x5e055a, 0x460ad5, 0x3296d2, 0x580752, 0x420ea5, 0x2c764a, 0x50064b, 0x3aea9b, 0x600aad,
+    0x4a056a, 0x34ab59, 0x5a0ba9, 0x460b52, 0x2e9b25, 0x540b25, 0x3f1a4b, 0x640a55, 0x4c0aad,
+    0x38f56c, 0x5e05b4, 0x480da9, 0x32bd92, 0x580e92, 0x420d25, 0x2c7a4d, 0x500a56, 0x3b12b6,
+    0x600ada, 0x4c06d4, 0x34aea9, 0x5a0f49, 0x460e92, 0x308d26, 0x52052b, 0x3d4a57, 0x62095b,
+    0x4e0b5a, 0x38d6d4, 0x5e0764, 0x480749, 0x32b693, 0x560a93, 0x40052b, 0x2a6a5b, 0x500aad,
+    0x3af56a, 0x600daa, 0x4c0ba4, 0x36bb49, 0x5a0d49, 0x440a95, 0x2e952d, 0x540536, 0x3c0aad,
+    0x2855aa, 0x4e05b2, 0x38cda5, 0x5c0ea5, 0x480d4a, 0x32aa96, 0x560a97, 0x400556, 0x2a6ab5,
+    0x500ad5, 0x3d16d2, 0x620752, 0x4c06a5, 0x36b64b, 0x5c064b, 0x440c9b, 0x30955a, 0x56056a,
+    0x400b69, 0x2a5752, 0x500b52, 0x3adb25, 0x600b25, 0x480a4b, 0x32b4ab, 0x5802ad, 0x42056d,
+    0x2c6b69, 0x520da9, 0x3efd92, 0x640e92, 0x4c0d25, 0x36da4d, 0x5c0a56, 0x4602b6, 0x2e95b5,
+    0x5606d4, 0x400ea9, 0x2c5e92, 0x500e92, 0x3acd26, 0x5e052b, 0x480a57, 0x32b4d6, 0x58035a,
+    0x4206d5, 0x2e76c9, 0x520749, 0x3d1693, 0x620a95, 0x4c052b, 0x34ca5b, 0x5a0aad, 0x46056a,
+    0x309b55, 0x560ba4, 0x400b49, 0x2a5a95, 0x500a95, 0x38f52d, 0x5e0556, 0x480ab5, 0x34b5aa,
+    0x5805d2, 0x420da5, 0x2e7d4a, 0x540e4a, 0x3d0c96, 0x600a97, 0x4c0556, 0x36cab5, 0x5a0ad9,
+    0x4606d2, 0x308ea5, 0x560725, 0x3e064b, 0x286c97, 0x4e049b, 0x38e55b, 0x5c056b, 0x480b69,
+    0x34b752, 0x5a0b52, 0x420b25, 0x2c9a4b, 0x520a4d, 0x3d14ab, 0x6002ad, 0x4a05ad, 0x36cb6a,
+    0x5c0da9, 0x460d92, 0x309d25, 0x560d25, 0x400a55, 0x2854ad, 0x4e04b6, 0x38e5b5, 0x5e06d5,
+    0x480ec9, 0x34be92, 0x5a0e92, 0x440d26, 0x2c6a56, 0x500a57, 0x3d1556, 0x62056a, 0x4a0b55,
+    0x36b6c9, 0x5c0749, 0x460693, 0x2e952b, 0x54052b, 0x3e0a5b, 0x2a555a, 0x4e056a, 0x38eb65,
+    0x5e0ba5, 0x4a0d49, 0x32ba95, 0x580a95, 0x42052d, 0x2c8aad, 0x500ab5, 0x3d35aa, 0x6205d2,
+    0x4c0da5, 0x36dd4a, 0x5c0e4a, 0x460c96, 0x30992e, 0x540556, 0x3e0ab5, 0x2a55b2, 0x5006d2,
+    0x38cea5, 0x5e0725, 0x48064b, 0x32ac97, 0x5604ab, 0x40055b, 0x2c6ada, 0x520b6a, 0x3d7752,
+    0x620b92, 0x4c0b25, 0x36da4b, 0x5a0a4d, 0x4404ad, 0x2ea95b, 0x5405ad, 0x3e0baa, 0x2a5b52,
+    0x500d92, 0x3afd25, 0x5e0d25, 0x480a55, 0x32b4ad, 0x5804b6, 0x4006b5, 0x2c6daa, 0x520eca,
+    0x3f0e92, 0x600e93, 0x4c0d26, 0x36ca56, 0x5a0a5b, 0x44055a, 0x2e8ad5, 0x540b55, 0x40074a,
+    0x286e93, 0x4e0a93, 0x38f52b, 0x5e052b, 0x460a9b, 0x32b55a, 0x58056a, 0x420b65, 0x2c974a,
+    0x520d4a, 0x3d1a95, 0x620a95, 0x4a092d, 0x34caad, 0x5a0ab5, 0x4605aa, 0x2e8ba5, 0x540ea5,
+    0x400d4a, 0x2a7d15, 0x4e0c96, 0x38f956, 0x5e0556, 0x480ab5, 0x32b6b4, 0x5806d4, 0x420ea5,
+    0x2e8e8a, 0x50068b, 0x3b1497, 0x6004ab, 0x4a095b, 0x34cada, 0x5a0b6a, 0x460754, 0x309725,
+    0x540b45, 0x3e0a8b, 0x28552b, 0x4e04ad, 0x38e96b, 0x5e05b5, 0x4a0daa, 0x36bb54, 0x5a0da2,
+    0x440d45, 0x2e9a8d, 0x540a95, 0x3d34ad, 0x6204d6, 0x4c0ab5, 0x38cdaa, 0x5c0eca, 0x480ea2,
+    0x329d46, 0x580d4a, 0x400a96, 0x2a7536, 0x50055a, 0x3aead5, 0x5e0b65, 0x4a0752, 0x34aea5,
+    0x5a0aa5, 0x42054b, 0x2c8a97, 0x520aab, 0x3f755a, 0x62056a, 0x4c0b65, 0x38db52, 0x5e0d52,
+    0x460b15, 0x30ba4b, 0x56094d, 0x400aad, 0x2a556a, 0x5005b2, 0x3aeda9, 0x600ea9, 0x4a0d92,
+    0x34bd15, 0x5a0d26, 0x440956, 0x2c92ad, 0x520ad6, 0x3e06d4, 0x282da9, 0x4c0ea9, 0x38ce8a,
+    0x5c068b, 0x460527, 0x2ea957, 0x54095b, 0x400ada, 0x2c76d4, 0x500754, 0x3af749, 0x600b45,
+    0x4a0a93, 0x32d52b, 0x58052d, 0x42096d, 0x2e936a, 0x520daa, 0x3f5ba4, 0x640da4, 0x4e0d49,
+    0x36da95, 0x5c0a96, 0x46052e, 0x30aaad, 0x540ab5, 0x400daa, 0x2c7da4, 0x520ea4, 0x3afd4a,
+    0x600d4a, 0x4a0a96, 0x34d536, 0x58055a, 0x420ad5, 0x2e96ca, 0x540752, 0x3c0ea5, 0x28564a,
+    0x4c064b, 0x36ca97, 0x5a0aab, 0x46055a, 0x30ab55, 0x560ba9, 0x400b52, 0x2a7b25, 0x500b25,
+    0x3afa4b, 0x5e0a4d, 0x480aad, 0x34d56a, 0x5a05b4,
+];
+
+pub struct MonthEntry {
+    pub month: u32,
+    pub is_leap: bool,
+    /// Julian day number of the first day
+    pub start: i64,
+    pub days: i64,
+}
+
+/// Months of the lunar year in chronological order, None outside of the table
+pub fn months(year: i32) -> Option<Vec<MonthEntry>> {
+    if !(TABLE_FIRST_YEAR..=TABLE_LAST_YEAR).contains(&year) {
+        return None;
+    }
+
+    let entry = LUNAR_YEARS[(year - TABLE_FIRST_YEAR) as usize];
+    let leap_month = (entry >> 13) & 0xf;
+    let mut start = jd_from_date(1, 1, year as i64) + (entry >> 17) as i64;
+
+    let mut months = vec![];
+    for i in 0..if leap_month == 0 { 12 } else { 13 } {
+        let (month, is_leap) = match leap_month {
+            0 => (i + 1, false),
+            leap if i < leap => (i + 1, false),
+            leap => (i, i == leap),
+        };
+        let days = if entry & (1 << i) != 0 { 30 } else { 29 };
+        months.push(MonthEntry {
+            month,
+            is_leap,
+            start,
+            days,
+        });
+        start += days;
+    }
+
+    Some(months)
+}
+
+/// Lunar date of the given julian day number, None outside of the table
+pub fn solar2lunar(day_number: i64) -> Option<LunarDate> {
+    let year = jd_to_date(day_number).year;
+    // the day is either in the lunar year starting in its solar year or in the previous one
+    for lunar_year in [year, year - 1] {
+        let Some(months) = months(lunar_year) else {
+            continue;
+        };
+        if day_number < months[0].start {
+            continue;
+        }
+        let month = months.iter().find(|m| day_number < m.start + m.days)?;
+
+        return Some(LunarDate::new(
+            lunar_year,
+            month.month,
+            (day_number - month.start + 1) as u32,
+            month.is_leap,
+        ));
+    }
+
+    None
+}
+
+/// Solar date of the given lunar date, None outside of the table
+pub fn lunar2solar(lunar_date: LunarDate) -> Option<Result<SolarDate, LunarDateError>> {
+    let months = months(lunar_date.year)?;
+    let month = months
+        .iter()
+        .find(|m| m.month == lunar_date.month && m.is_leap == lunar_date.is_leap);
+
+    Some(match month {
+        None => Err(LunarDateError::NoSuchLeapMonth {
+            year: lunar_date.year,
+            month: lunar_date.month,
+        }),
+        Some(month) if lunar_date.day < 1 || lunar_date.day as i64 > month.days => {
+            Err(LunarDateError::DayOutOfRange {
+                day: lunar_date.day,
+                days_in_month: month.days as u32,
+            })
+        }
+        Some(month) => Ok(jd_to_date(month.start + lunar_date.day as i64 - 1)),
+    })
+}
+
+// the table follows the default model only
+#[cfg(all(test, not(feature = "precise")))]
+mod tests {
+    use super::*;
+    use crate::lunar_year::astronomical_year_info;
+
+    fn encode(year: i32) -> u32 {
+        let info = astronomical_year_info(year, &Astronomical);
+        let first = &info.months[0].start;
+        let tet = jd_from_date(first.day as i64, first.month as i64, first.year as i64);
+        let mut entry = ((tet - jd_from_date(1, 1, year as i64)) as u32) << 17;
+        entry |= info.leap_month.unwrap_or(0) << 13;
+        for (i, month) in info.months.iter().enumerate() {
+            if month.days == 30 {
+                entry |= 1 << i;
+            }
+        }
+
+        entry
+    }
+
+    // UTC+7 without a fixed offset, so conversions use the astronomical model
+    struct Astronomical;
+
+    impl crate::TimeZoneRule for Astronomical {
+        fn offset_minutes(&self, _jd: i64) -> i64 {
+            TABLE_OFFSET_MINUTES
+        }
+    }
+
+    #[test]
+    fn table_matches_astronomical_test() {
+        for year in TABLE_FIRST_YEAR..=TABLE_LAST_YEAR {
+            assert_eq!(
+                encode(year),
+                LUNAR_YEARS[(year - TABLE_FIRST_YEAR) as usize],
+                "{}",
+                year
+            );
+        }
+    }
+
+    #[test]
+    fn solar2lunar_test() {
+        let first = jd_from_date(25, 1, 1800);
+        let last = jd_from_date(31, 12, 2200);
+        for day_number in (first..=last).step_by(5) {
+            let solar_date = jd_to_date(day_number);
+            assert_eq!(
+                crate::solar2lunar(solar_date, Astronomical).to_string(),
+                solar2lunar(day_number).unwrap().to_string(),
+                "{}",
+                solar_date
+            );
+        }
+
+        // outside of the table
+        assert!(solar2lunar(jd_from_date(24, 1, 1800)).is_none());
+        assert!(solar2lunar(jd_from_date(1, 6, 2201)).is_none());
+    }
+
+    #[test]
+    fn lunar2solar_test() {
+        for year in [1800, 1900, 1968, 1985, 2025, 2033, 2199] {
+            for info in astronomical_year_info(year, &Astronomical).months {
+                for day in [1, info.days] {
+                    let lunar_date = LunarDate::new(year, info.month, day, info.is_leap);
+                    let expected = crate::lunar2solar(lunar_date, Astronomical);
+                    let actual = lunar2solar(lunar_date).unwrap().ok().unwrap();
+                    assert_eq!(expected.to_string(), actual.to_string(), "{}", lunar_date);
+                }
+            }
+        }
+
+        let lunar_date = LunarDate::new(2024, 12, 30, false);
+        assert_eq!(
+            Some(LunarDateError::DayOutOfRange {
+                day: 30,
+                days_in_month: 29
+            }),
+            lunar2solar(lunar_date).unwrap().err()
+        );
+        assert!(lunar2solar(LunarDate::new(2201, 1, 1, false)).is_none());
+    }
+}
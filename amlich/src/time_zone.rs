@@ -0,0 +1,166 @@
+use super::fns::jd_from_date;
+
+/// Offset from UTC used by the calendar calculation. The offset decides on which
+/// local day a new moon or a solar term falls, so it must be the one in force
+/// at the time for historical dates.
+pub trait TimeZoneRule {
+    /// Offset from UTC in minutes in force on the given julian day number
+    fn offset_minutes(&self, jd: i64) -> i64;
+
+    /// The offset in minutes if it is the same at every date, conversions in UTC+7
+    /// then use a precomputed table
+    fn fixed_offset_minutes(&self) -> Option<i64> {
+        None
+    }
+}
+
+/// A fixed offset in hours, e.g. 7 for Vietnam today
+impl TimeZoneRule for i64 {
+    fn offset_minutes(&self, _jd: i64) -> i64 {
+        self * 60
+    }
+
+    fn fixed_offset_minutes(&self) -> Option<i64> {
+        Some(self * 60)
+    }
+}
+
+// (year, month, day, offset in minutes) from which the offset applies
+type Transition = (i64, i64, i64, i64);
+
+fn offset_from_history(history: &[Transition], jd: i64) -> i64 {
+    let mut offset = history[0].3;
+    for &(year, month, day, minutes) in history {
+        if jd < jd_from_date(day, month, year) {
+            break;
+        }
+        offset = minutes;
+    }
+
+    offset
+}
+
+// Hà Nội: Indochina time until the Japanese occupation, UTC+8 from 1947
+// until North Vietnam moved to UTC+7 on 8 August 1967
+const NORTH_VIETNAM_HISTORY: [Transition; 6] = [
+    (-9999, 1, 1, 7 * 60),
+    (1943, 1, 1, 8 * 60),
+    (1945, 3, 15, 9 * 60),
+    (1945, 9, 2, 7 * 60),
+    (1947, 4, 1, 8 * 60),
+    (1967, 8, 8, 7 * 60),
+];
+
+// Sài Gòn: back to UTC+7 in 1955, UTC+8 from 1960 until 13 June 1975
+const SOUTH_VIETNAM_HISTORY: [Transition; 8] = [
+    (-9999, 1, 1, 7 * 60),
+    (1943, 1, 1, 8 * 60),
+    (1945, 3, 15, 9 * 60),
+    (1945, 9, 2, 7 * 60),
+    (1947, 4, 1, 8 * 60),
+    (1955, 7, 1, 7 * 60),
+    (1960, 1, 1, 8 * 60),
+    (1975, 6, 13, 7 * 60),
+];
+
+// Seoul: UTC+8:30 from 1908, Japanese standard time from 1912, back to UTC+8:30
+// between 1954 and 10 August 1961
+const KOREA_HISTORY: [Transition; 4] = [
+    (-9999, 1, 1, 8 * 60 + 30),
+    (1912, 1, 1, 9 * 60),
+    (1954, 3, 21, 8 * 60 + 30),
+    (1961, 8, 10, 9 * 60),
+];
+
+/// Historical time zone rules of Vietnam. The two regions differ between 1955 and 1975,
+/// e.g. Tết Mậu Thân 1968 was on 29 January in the North and 30 January in the South.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub enum VietnamRegion {
+    #[default]
+    North,
+    South,
+}
+
+impl TimeZoneRule for VietnamRegion {
+    fn offset_minutes(&self, jd: i64) -> i64 {
+        match self {
+            VietnamRegion::North => offset_from_history(&NORTH_VIETNAM_HISTORY, jd),
+            VietnamRegion::South => offset_from_history(&SOUTH_VIETNAM_HISTORY, jd),
+        }
+    }
+}
+
+/// Time zone to convert dates with, either a fixed offset in hours or a historical rule
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TimeZone {
+    Fixed(i64),
+    Vietnam(VietnamRegion),
+    /// Historical rule of Korea, used by the Korean calendar
+    Korea,
+}
+
+impl TimeZoneRule for TimeZone {
+    fn offset_minutes(&self, jd: i64) -> i64 {
+        match self {
+            TimeZone::Fixed(hours) => hours.offset_minutes(jd),
+            TimeZone::Vietnam(region) => region.offset_minutes(jd),
+            TimeZone::Korea => offset_from_history(&KOREA_HISTORY, jd),
+        }
+    }
+
+    fn fixed_offset_minutes(&self) -> Option<i64> {
+        match self {
+            TimeZone::Fixed(hours) => hours.fixed_offset_minutes(),
+            _ => None,
+        }
+    }
+}
+
+impl From<i64> for TimeZone {
+    fn from(hours: i64) -> Self {
+        TimeZone::Fixed(hours)
+    }
+}
+
+impl From<VietnamRegion> for TimeZone {
+    fn from(region: VietnamRegion) -> Self {
+        TimeZone::Vietnam(region)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vietnam_region_test() {
+        let jd = |y, m, d| jd_from_date(d, m, y);
+        assert_eq!(420, VietnamRegion::North.offset_minutes(jd(1930, 1, 1)));
+        assert_eq!(480, VietnamRegion::North.offset_minutes(jd(1960, 1, 1)));
+        assert_eq!(480, VietnamRegion::North.offset_minutes(jd(1967, 8, 7)));
+        assert_eq!(420, VietnamRegion::North.offset_minutes(jd(1967, 8, 8)));
+        assert_eq!(420, VietnamRegion::North.offset_minutes(jd(2024, 1, 1)));
+
+        assert_eq!(420, VietnamRegion::South.offset_minutes(jd(1957, 1, 1)));
+        assert_eq!(480, VietnamRegion::South.offset_minutes(jd(1968, 1, 30)));
+        assert_eq!(420, VietnamRegion::South.offset_minutes(jd(1975, 6, 13)));
+        assert_eq!(420, VietnamRegion::South.offset_minutes(jd(2024, 1, 1)));
+    }
+
+    #[test]
+    fn time_zone_test() {
+        assert_eq!(420, TimeZone::from(7).offset_minutes(0));
+        assert_eq!(
+            480,
+            TimeZone::from(VietnamRegion::South).offset_minutes(jd_from_date(1, 1, 1970))
+        );
+        assert_eq!(
+            510,
+            TimeZone::Korea.offset_minutes(jd_from_date(1, 1, 1960))
+        );
+        assert_eq!(
+            540,
+            TimeZone::Korea.offset_minutes(jd_from_date(10, 8, 1961))
+        );
+    }
+}
@@ -5,7 +5,10 @@ use actix_web::{middleware, web, App, HttpServer};
 use log::info;
 use ramlich::handlers::amlich_com_proxy::{amlich_com_calendar_proxy, amlich_com_forward};
 use ramlich::handlers::middleware::kafka_request_event_reporter;
-use ramlich::handlers::{get_month_route, lunar_route, today_route, ApiDoc};
+use ramlich::handlers::{
+    almanac_route, calendar_ics_route, four_pillars_route, get_month_route, holidays_route,
+    lunar_route, moon_route, solar_route, today_route, ApiDoc,
+};
 use ramlich::kafka::KafkaProducer;
 use ramlich::unleash::{init_client, sync_features};
 use utoipa::OpenApi;
@@ -46,7 +49,13 @@ async fn main() -> std::io::Result<()> {
             )
             .service(today_route)
             .service(lunar_route)
+            .service(solar_route)
             .service(get_month_route)
+            .service(holidays_route)
+            .service(calendar_ics_route)
+            .service(moon_route)
+            .service(almanac_route)
+            .service(four_pillars_route)
             .service(amlich_com_calendar_proxy)
             .service(web::resource("/healthcheck").to(|| async { "OK" }))
             .default_service(web::to(amlich_com_forward))
@@ -0,0 +1,47 @@
+extern crate vncalendar;
+
+use super::converters::{almanac_to_response, vietnam_midday};
+
+use actix_web::{get, HttpMessage, HttpRequest, HttpResponse};
+use chrono::NaiveDate;
+
+use crate::{
+    models::RequestEventId,
+    requests::AlmanacQuery,
+    responses::{AlmanacResponse, ErrorResponse, ResponseMeta},
+};
+
+#[utoipa::path(
+    get,
+    path = "/almanac",
+    params(AlmanacQuery),
+    responses(
+        (status = 200, description = "Day god, hoàng đạo and lucky hours of a solar date", body = AlmanacResponse),
+        (status = 400, description = "Invalid date"),
+    )
+)]
+#[get("/almanac")]
+pub async fn almanac_route(
+    request: HttpRequest,
+    data: actix_web::web::Query<AlmanacQuery>,
+) -> HttpResponse {
+    let solar_date = match NaiveDate::parse_from_str(&data.solar_date, "%Y-%m-%d") {
+        Err(error) => {
+            return HttpResponse::BadRequest().json(ErrorResponse::new(error.to_string()));
+        }
+        Ok(solar) => solar,
+    };
+
+    let request_event_id = request
+        .extensions()
+        .get::<RequestEventId>()
+        .unwrap()
+        .clone();
+
+    let t = vncalendar::time::VNDate::new(vietnam_midday(solar_date), vncalendar::TIME_ZONE_OFFSET);
+
+    HttpResponse::Ok().json(AlmanacResponse::new(
+        almanac_to_response(&t),
+        ResponseMeta::new(request_event_id),
+    ))
+}
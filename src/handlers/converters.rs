@@ -1,11 +1,134 @@
+extern crate amlich;
 extern crate vncalendar;
 
-use crate::models::VNDate;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, TimeZone, Utc};
 
-pub fn date_to_response(vndate: &vncalendar::time::VNDate) -> VNDate {
+use crate::{
+    models::{Almanac, DayGod, FourPillars, Holiday, LuckyHour, MoonPhase, Pillar, VNDate},
+    requests::{CalendarSystem, HolidayKind, Language, LanguageQuery},
+};
+
+pub fn calendar_system(calendar: Option<CalendarSystem>) -> amlich::CalendarSystem {
+    match calendar {
+        None | Some(CalendarSystem::Vietnamese) => amlich::CalendarSystem::Vietnamese,
+        Some(CalendarSystem::Chinese) => amlich::CalendarSystem::Chinese,
+        Some(CalendarSystem::Korean) => amlich::CalendarSystem::Korean,
+    }
+}
+
+pub fn holiday_kind(kind: HolidayKind) -> vncalendar::holidays::HolidayKind {
+    match kind {
+        HolidayKind::Official => vncalendar::holidays::HolidayKind::Official,
+        HolidayKind::Traditional => vncalendar::holidays::HolidayKind::Traditional,
+    }
+}
+
+/// 12:00 in Vietnam (UTC+7) on the given solar date
+pub fn vietnam_midday(date: NaiveDate) -> DateTime<Utc> {
+    let offset = FixedOffset::east_opt(vncalendar::TIME_ZONE_OFFSET as i32 * 3600).unwrap();
+    offset
+        .from_local_datetime(&date.and_time(NaiveTime::from_hms_opt(12, 0, 0).unwrap()))
+        .unwrap()
+        .to_utc()
+}
+
+pub fn date_to_response(vndate: &vncalendar::time::VNDate, lang: &LanguageQuery) -> VNDate {
     let solar = format!("{}", vndate.get_solar_datetime().date_naive());
     let lunar = format!("{}", vndate.get_lunar_date());
     let is_leap = vndate.is_leap();
+    let text = match lang.lang {
+        Some(Language::Vi) => Some(vndate.to_vietnamese_text(lang.han_nom.unwrap_or(false))),
+        None | Some(Language::En) => None,
+    };
+
+    VNDate::new(lunar, solar, is_leap).with_text(text)
+}
+
+pub fn holiday_to_response(holiday: &vncalendar::holidays::Holiday) -> Holiday {
+    let solar = format!("{}", holiday.date.get_solar_datetime().date_naive());
+    let lunar = format!("{}", holiday.date.get_lunar_date());
+
+    Holiday::new(
+        holiday.id.to_string(),
+        holiday.name_vi.to_string(),
+        holiday.name_en.to_string(),
+        lunar,
+        solar,
+        holiday.date.is_leap(),
+        holiday.is_day_off,
+    )
+}
+
+pub fn moon_phase_to_response(phase: &amlich::MoonPhaseTime) -> MoonPhase {
+    let id = match phase.phase {
+        amlich::MoonPhase::NewMoon => "new_moon",
+        amlich::MoonPhase::FirstQuarter => "first_quarter",
+        amlich::MoonPhase::FullMoon => "full_moon",
+        amlich::MoonPhase::LastQuarter => "last_quarter",
+    };
+
+    MoonPhase::new(
+        id.to_string(),
+        phase.phase.name().to_string(),
+        phase.time.to_rfc3339(),
+    )
+}
+
+fn day_god_to_response(god: vncalendar::almanac::DayGod) -> DayGod {
+    DayGod::new(
+        god.id().to_string(),
+        god.name().to_string(),
+        god.is_auspicious(),
+    )
+}
+
+pub fn almanac_to_response(vndate: &vncalendar::time::VNDate) -> Almanac {
+    let almanac = vndate.almanac();
+    let lucky_hours = almanac
+        .lucky_hours
+        .iter()
+        .map(|hour| {
+            LuckyHour::new(
+                hour.branch.name().to_string(),
+                day_god_to_response(hour.god),
+                format!("{:02}:00", hour.start_hour),
+                format!("{:02}:00", hour.end_hour),
+            )
+        })
+        .collect();
+
+    Almanac::new(
+        format!("{}", vndate.get_solar_datetime().date_naive()),
+        format!("{}", vndate.get_lunar_date()),
+        vndate.is_leap(),
+        almanac.day_can_chi.to_string(),
+        day_god_to_response(almanac.day_god),
+        lucky_hours,
+        almanac.truc.to_string(),
+        almanac.mansion.to_string(),
+    )
+}
+
+fn pillar_to_response(can_chi: amlich::CanChi) -> Pillar {
+    let nap_am = can_chi.nap_am();
+    Pillar::new(
+        can_chi.to_string(),
+        can_chi.han(),
+        nap_am.name().to_string(),
+        nap_am.element().name().to_string(),
+    )
+}
 
-    VNDate::new(lunar, solar, is_leap)
+pub fn four_pillars_to_response(
+    birth: &chrono::DateTime<chrono::FixedOffset>,
+    pillars: &vncalendar::pillars::FourPillars,
+) -> FourPillars {
+    FourPillars::new(
+        birth.to_rfc3339(),
+        pillars.solar_time.format("%Y-%m-%dT%H:%M:%S").to_string(),
+        pillar_to_response(pillars.year),
+        pillar_to_response(pillars.month),
+        pillar_to_response(pillars.day),
+        pillar_to_response(pillars.hour),
+    )
 }
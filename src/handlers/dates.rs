@@ -10,28 +10,82 @@ use vncalendar::Month;
 
 use crate::{
     models::{RequestEventId, VNDate},
-    requests::SolarToLunarDates,
+    requests::{DatesCalendar, LanguageQuery, SolarToLunarDates},
     responses::{ErrorResponse, ResponseMeta, YearDatesResponse, YearMonthDatesResponse},
 };
 
+fn get_lunar_dates(
+    data: &SolarToLunarDates,
+    lang: &LanguageQuery,
+    request_event_id: RequestEventId,
+) -> HttpResponse {
+    let year = data.year;
+    if let Some(month) = data.month {
+        let dates = match vncalendar::get_lunar_month_dates(
+            year,
+            month as u32,
+            data.is_leap.unwrap_or(false),
+        ) {
+            Err(error) => {
+                return HttpResponse::BadRequest().json(ErrorResponse::new(error.to_string()));
+            }
+            Ok(dates) => dates,
+        };
+
+        let dates_response: Vec<VNDate> = dates
+            .iter()
+            .map(|date| date_to_response(date, lang))
+            .collect();
+        let response =
+            YearMonthDatesResponse::new(dates_response, ResponseMeta::new(request_event_id));
+        return HttpResponse::Ok().json(response);
+    }
+
+    let months = match vncalendar::get_lunar_year_dates(year) {
+        Err(error) => {
+            return HttpResponse::BadRequest().json(ErrorResponse::new(error.to_string()));
+        }
+        Ok(months) => months,
+    };
+    let data: HashMap<String, Vec<VNDate>> = months
+        .iter()
+        .map(|(m, dates)| {
+            let dates_response = dates
+                .iter()
+                .map(|date| date_to_response(date, lang))
+                .collect();
+            (format!("{}", m), dates_response)
+        })
+        .collect();
+    let response = YearDatesResponse::new(data, ResponseMeta::new(request_event_id));
+
+    HttpResponse::Ok().json(response)
+}
+
 #[utoipa::path(
     get,
     path = "/dates",
-    params(SolarToLunarDates),
+    params(SolarToLunarDates, LanguageQuery),
     responses(
         (status = 200, description = "List of all dates in given month", body = YearMonthDatesResponse),
+        (status = 400, description = "Invalid month or year out of range"),
     )
 )]
 #[get("/dates")]
 pub async fn get_month_route(
     request: HttpRequest,
     data: actix_web::web::Query<SolarToLunarDates>,
+    lang: actix_web::web::Query<LanguageQuery>,
 ) -> HttpResponse {
     let request_event_id = request
         .extensions()
         .get::<RequestEventId>()
         .unwrap()
         .clone();
+    if data.calendar == Some(DatesCalendar::Lunar) {
+        return get_lunar_dates(&data, &lang, request_event_id);
+    }
+
     let year = data.year;
     if data.month != None {
         let month = Month::try_from(data.month.unwrap());
@@ -44,7 +98,7 @@ pub async fn get_month_route(
         let res = vncalendar::get_month_dates(year, month.unwrap());
         let mut dates_reponse: Vec<VNDate> = Vec::new();
         for date in res {
-            dates_reponse.push(date_to_response(&date));
+            dates_reponse.push(date_to_response(&date, &lang));
         }
         let response =
             YearMonthDatesResponse::new(dates_reponse, ResponseMeta::new(request_event_id));
@@ -56,7 +110,7 @@ pub async fn get_month_route(
     for (m, dates) in res.iter() {
         let mut dates_reponse: Vec<VNDate> = Vec::new();
         for date in dates {
-            dates_reponse.push(date_to_response(date));
+            dates_reponse.push(date_to_response(date, &lang));
         }
         data.insert(format!("{}", *m as isize), dates_reponse);
     }
@@ -0,0 +1,61 @@
+extern crate vncalendar;
+
+use super::converters::{holiday_kind, holiday_to_response};
+
+use actix_web::{get, HttpMessage, HttpRequest, HttpResponse};
+use vncalendar::Month;
+
+use crate::{
+    models::{Holiday, RequestEventId},
+    requests::HolidaysQuery,
+    responses::{ErrorResponse, HolidaysResponse, ResponseMeta},
+};
+
+#[utoipa::path(
+    get,
+    path = "/holidays",
+    params(HolidaysQuery),
+    responses(
+        (status = 200, description = "Holidays in given year, optionally filtered by month and kind", body = HolidaysResponse),
+    )
+)]
+#[get("/holidays")]
+pub async fn holidays_route(
+    request: HttpRequest,
+    data: actix_web::web::Query<HolidaysQuery>,
+) -> HttpResponse {
+    let request_event_id = request
+        .extensions()
+        .get::<RequestEventId>()
+        .unwrap()
+        .clone();
+
+    let mut month: Option<u32> = None;
+    if data.month != None {
+        match Month::try_from(data.month.unwrap()) {
+            Err(error) => {
+                return HttpResponse::BadRequest().json(ErrorResponse::new(error.to_string()));
+            }
+            Ok(m) => month = Some(m as u32),
+        }
+    }
+
+    let mut holidays_response: Vec<Holiday> = Vec::new();
+    for holiday in vncalendar::holidays::get_holidays(data.year) {
+        if month.is_some_and(|m| holiday.date.solar_month() != m) {
+            continue;
+        }
+        if data
+            .kind
+            .is_some_and(|kind| holiday_kind(kind) != holiday.kind)
+        {
+            continue;
+        }
+        holidays_response.push(holiday_to_response(&holiday));
+    }
+
+    HttpResponse::Ok().json(HolidaysResponse::new(
+        holidays_response,
+        ResponseMeta::new(request_event_id),
+    ))
+}
@@ -0,0 +1,51 @@
+extern crate amlich;
+extern crate vncalendar;
+
+use actix_web::{get, HttpResponse};
+
+use crate::{
+    requests::{CalendarFeed, CalendarFeedQuery},
+    responses::ErrorResponse,
+};
+
+const MAX_YEARS: i32 = 10;
+
+#[utoipa::path(
+    get,
+    path = "/calendar.ics",
+    params(CalendarFeedQuery),
+    responses(
+        (status = 200, description = "iCalendar feed of lunar dates or holidays", content_type = "text/calendar"),
+        (status = 400, description = "Invalid year range or year out of range", body = ErrorResponse),
+    )
+)]
+#[get("/calendar.ics")]
+pub async fn calendar_ics_route(data: actix_web::web::Query<CalendarFeedQuery>) -> HttpResponse {
+    let from = data.from;
+    let to = data.to.unwrap_or(from);
+
+    if let Some(year) = [from, to]
+        .into_iter()
+        .find(|year| !(amlich::MIN_YEAR..=amlich::MAX_YEAR).contains(year))
+    {
+        return HttpResponse::BadRequest().json(ErrorResponse::new(
+            amlich::LunarDateError::YearOutOfRange(year).to_string(),
+        ));
+    }
+
+    if to < from || to - from >= MAX_YEARS {
+        return HttpResponse::BadRequest().json(ErrorResponse::new(format!(
+            "Invalid year range, to must be between from and from + {}",
+            MAX_YEARS - 1
+        )));
+    }
+
+    let body = match data.feed.unwrap_or(CalendarFeed::Lunar) {
+        CalendarFeed::Lunar => vncalendar::ical::lunar_dates_calendar(from, to),
+        CalendarFeed::Holidays => vncalendar::ical::holidays_calendar(from, to),
+    };
+
+    HttpResponse::Ok()
+        .content_type("text/calendar; charset=utf-8")
+        .body(body)
+}
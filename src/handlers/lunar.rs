@@ -1,22 +1,21 @@
 extern crate amlich;
 extern crate vncalendar;
 
-use super::converters::date_to_response;
+use super::converters::{calendar_system, date_to_response};
 
 use actix_web::{get, HttpMessage, HttpRequest, HttpResponse};
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
-use vncalendar::TIME_ZONE_OFFSET;
 
 use crate::{
     models::RequestEventId,
-    requests::SolarToLunar,
+    requests::{LanguageQuery, SolarToLunar},
     responses::{ErrorResponse, ResponseMeta, VNDateResponse},
 };
 
 #[utoipa::path(
     get,
     path = "/lunar",
-    params(SolarToLunar),
+    params(SolarToLunar, LanguageQuery),
     responses(
         (status = 200, description = "Convert solar date and lunar date", body = VNDateResponse),
     )
@@ -25,6 +24,7 @@ use crate::{
 pub async fn lunar_route(
     request: HttpRequest,
     solar: actix_web::web::Query<SolarToLunar>,
+    lang: actix_web::web::Query<LanguageQuery>,
 ) -> HttpResponse {
     let solar_date = match NaiveDate::parse_from_str(&solar.solar_date, "%Y-%m-%d") {
         Err(error) => {
@@ -40,10 +40,13 @@ pub async fn lunar_route(
         .clone();
 
     let midday = NaiveDateTime::new(solar_date, NaiveTime::from_hms_opt(12, 0, 0).unwrap());
-    let t = vncalendar::time::VNDate::new(midday.and_utc(), TIME_ZONE_OFFSET);
+    let t = vncalendar::time::VNDate::new_with_calendar(
+        midday.and_utc(),
+        calendar_system(solar.calendar),
+    );
 
     HttpResponse::Ok().json(VNDateResponse::new_with_meta(
-        date_to_response(&t),
+        date_to_response(&t, &lang),
         ResponseMeta::new(request_event_id),
     ))
 }
@@ -9,9 +9,27 @@ pub use today::today_route;
 mod lunar;
 pub use lunar::lunar_route;
 
+mod solar;
+pub use solar::solar_route;
+
 mod dates;
 pub use dates::get_month_route;
 
+mod holidays;
+pub use holidays::holidays_route;
+
+mod ical;
+pub use ical::calendar_ics_route;
+
+mod moon;
+pub use moon::moon_route;
+
+mod almanac;
+pub use almanac::almanac_route;
+
+mod pillars;
+pub use pillars::four_pillars_route;
+
 use utoipa::OpenApi;
 
 pub mod middleware;
@@ -19,8 +37,14 @@ pub mod middleware;
 pub mod amlich_com_proxy;
 
 use crate::{
-    models::VNDate,
-    responses::{VNDateResponse, YearDatesResponse, YearMonthDatesResponse},
+    models::{
+        Almanac, DayGod, FourPillars, Holiday, LuckyHour, Moon, MoonDay, MoonPhase, Pillar, VNDate,
+    },
+    requests::{CalendarSystem, HolidayKind, Language},
+    responses::{
+        AlmanacResponse, ErrorResponse, FourPillarsResponse, HolidaysResponse, MoonResponse,
+        VNDateResponse, YearDatesResponse, YearMonthDatesResponse,
+    },
 };
 
 #[derive(OpenApi)]
@@ -29,7 +53,13 @@ use crate::{
     paths(
         today::today_route,
         lunar::lunar_route,
+        solar::solar_route,
         dates::get_month_route,
+        holidays::holidays_route,
+        ical::calendar_ics_route,
+        moon::moon_route,
+        almanac::almanac_route,
+        pillars::four_pillars_route,
         amlich_com_proxy::amlich_com_calendar_proxy
     ),
     components(schemas(
@@ -37,6 +67,23 @@ use crate::{
         YearDatesResponse,
         YearMonthDatesResponse,
         VNDate,
+        HolidaysResponse,
+        Holiday,
+        HolidayKind,
+        CalendarSystem,
+        Language,
+        MoonResponse,
+        Moon,
+        MoonPhase,
+        MoonDay,
+        AlmanacResponse,
+        Almanac,
+        DayGod,
+        LuckyHour,
+        FourPillarsResponse,
+        FourPillars,
+        Pillar,
+        ErrorResponse,
         amlich_com_proxy::AmLichCalendarResult,
         amlich_com_proxy::AmLichCalendar,
     ),)
@@ -0,0 +1,80 @@
+extern crate amlich;
+
+use super::converters::{moon_phase_to_response, vietnam_midday};
+
+use actix_web::{get, HttpMessage, HttpRequest, HttpResponse};
+use chrono::{Datelike, NaiveDate};
+
+use crate::{
+    models::{Moon, MoonDay, RequestEventId},
+    requests::MoonQuery,
+    responses::{ErrorResponse, MoonResponse, ResponseMeta},
+};
+
+const MAX_DAYS: i64 = 366;
+
+fn to_solar_date(date: NaiveDate) -> amlich::SolarDate {
+    amlich::SolarDate::new(date.year(), date.month(), date.day())
+}
+
+#[utoipa::path(
+    get,
+    path = "/moon",
+    params(MoonQuery),
+    responses(
+        (status = 200, description = "Moon phases and daily illumination between two solar dates", body = MoonResponse),
+        (status = 400, description = "Invalid date or date range"),
+    )
+)]
+#[get("/moon")]
+pub async fn moon_route(
+    request: HttpRequest,
+    data: actix_web::web::Query<MoonQuery>,
+) -> HttpResponse {
+    let from = NaiveDate::parse_from_str(&data.from, "%Y-%m-%d");
+    let to = NaiveDate::parse_from_str(&data.to, "%Y-%m-%d");
+    let (from, to) = match (from, to) {
+        (Ok(from), Ok(to)) => (from, to),
+        (Err(error), _) | (_, Err(error)) => {
+            return HttpResponse::BadRequest().json(ErrorResponse::new(error.to_string()));
+        }
+    };
+
+    let days = (to - from).num_days();
+    if !(0..MAX_DAYS).contains(&days) {
+        return HttpResponse::BadRequest().json(ErrorResponse::new(format!(
+            "Invalid date range, to must be between from and {} days later",
+            MAX_DAYS - 1
+        )));
+    }
+
+    let request_event_id = request
+        .extensions()
+        .get::<RequestEventId>()
+        .unwrap()
+        .clone();
+
+    let phases = amlich::moon_phases(
+        to_solar_date(from),
+        to_solar_date(to),
+        amlich::CalendarSystem::Vietnamese,
+    );
+
+    let mut moon_days: Vec<MoonDay> = Vec::new();
+    for date in from.iter_days().take(days as usize + 1) {
+        let state = amlich::moon_state(vietnam_midday(date));
+        moon_days.push(MoonDay::new(
+            format!("{}", date),
+            state.phase,
+            state.illumination,
+        ));
+    }
+
+    HttpResponse::Ok().json(MoonResponse::new(
+        Moon::new(
+            phases.iter().map(moon_phase_to_response).collect(),
+            moon_days,
+        ),
+        ResponseMeta::new(request_event_id),
+    ))
+}
@@ -0,0 +1,55 @@
+extern crate vncalendar;
+
+use super::converters::four_pillars_to_response;
+
+use actix_web::{get, HttpMessage, HttpRequest, HttpResponse};
+use chrono::DateTime;
+
+use crate::{
+    models::RequestEventId,
+    requests::FourPillarsQuery,
+    responses::{ErrorResponse, FourPillarsResponse, ResponseMeta},
+};
+
+#[utoipa::path(
+    get,
+    path = "/four-pillars",
+    params(FourPillarsQuery),
+    responses(
+        (status = 200, description = "Year, month, day and hour pillars of a birth, the month changing at solar terms", body = FourPillarsResponse),
+        (status = 400, description = "Invalid birth time or longitude"),
+    )
+)]
+#[get("/four-pillars")]
+pub async fn four_pillars_route(
+    request: HttpRequest,
+    data: actix_web::web::Query<FourPillarsQuery>,
+) -> HttpResponse {
+    let birth = match DateTime::parse_from_rfc3339(&data.birth) {
+        Err(error) => {
+            return HttpResponse::BadRequest().json(ErrorResponse::new(error.to_string()));
+        }
+        Ok(birth) => birth,
+    };
+
+    if let Some(longitude) = data.longitude {
+        if !(-180.0..=180.0).contains(&longitude) {
+            return HttpResponse::BadRequest().json(ErrorResponse::new(
+                "Longitude must be between -180 and 180".to_string(),
+            ));
+        }
+    }
+
+    let request_event_id = request
+        .extensions()
+        .get::<RequestEventId>()
+        .unwrap()
+        .clone();
+
+    let pillars = vncalendar::pillars::four_pillars(birth, data.longitude);
+
+    HttpResponse::Ok().json(FourPillarsResponse::new(
+        four_pillars_to_response(&birth, &pillars),
+        ResponseMeta::new(request_event_id),
+    ))
+}
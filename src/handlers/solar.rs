@@ -0,0 +1,88 @@
+extern crate amlich;
+extern crate vncalendar;
+
+use super::converters::{calendar_system, date_to_response};
+
+use actix_web::{get, HttpMessage, HttpRequest, HttpResponse};
+use chrono::{NaiveDateTime, NaiveTime};
+
+use crate::{
+    models::RequestEventId,
+    requests::{LanguageQuery, LunarToSolar},
+    responses::{ErrorResponse, ResponseMeta, VNDateResponse},
+};
+
+const LUNAR_DATE_FORMAT_ERROR: &str =
+    "Invalid lunar date format, should be yyyy-mm-dd, dd/mm/yyyy or Vietnamese text";
+const SOLAR_DATE_RANGE_ERROR: &str = "The solar date is out of the supported range";
+const LEAP_MONTH_CONFLICT_ERROR: &str =
+    "is_leap is false but lunar_date is in a leap month, remove one of them";
+
+#[utoipa::path(
+    get,
+    path = "/solar",
+    params(LunarToSolar, LanguageQuery),
+    responses(
+        (status = 200, description = "Convert lunar date to solar date", body = VNDateResponse),
+        (status = 400, description = "Invalid or non-existing lunar date", body = ErrorResponse),
+    )
+)]
+#[get("/solar")]
+pub async fn solar_route(
+    request: HttpRequest,
+    lunar: actix_web::web::Query<LunarToSolar>,
+    lang: actix_web::web::Query<LanguageQuery>,
+) -> HttpResponse {
+    let calendar = calendar_system(lunar.calendar);
+    // a date without year is in the current lunar year
+    let reference_year = vncalendar::time::VNDate::today().year();
+    let mut lunar_date = match amlich::parse_lunar_date(&lunar.lunar_date, reference_year, calendar)
+    {
+        Err(amlich::ParseDateError::InvalidFormat) => {
+            return HttpResponse::BadRequest()
+                .json(ErrorResponse::new(LUNAR_DATE_FORMAT_ERROR.to_string()));
+        }
+        Err(error) => {
+            return HttpResponse::BadRequest().json(ErrorResponse::new(error.to_string()));
+        }
+        Ok(lunar_date) => lunar_date,
+    };
+    // the leap month is given by the flag, by the date (2023-02-10L, "nhuận") or both
+    match (lunar.is_leap, lunar_date.is_leap) {
+        (Some(false), true) => {
+            return HttpResponse::BadRequest()
+                .json(ErrorResponse::new(LEAP_MONTH_CONFLICT_ERROR.to_string()));
+        }
+        (Some(true), _) => lunar_date.is_leap = true,
+        _ => {}
+    }
+
+    let solar = match amlich::try_lunar2solar(lunar_date, calendar) {
+        Err(error) => {
+            return HttpResponse::BadRequest().json(ErrorResponse::new(error.to_string()));
+        }
+        Ok(solar) => solar,
+    };
+
+    // before 1582 the solar date is in the Julian calendar, chrono is proleptic Gregorian
+    let solar_date = match amlich::JulianDay::from_solar_date(solar).to_naive_date() {
+        None => {
+            return HttpResponse::BadRequest()
+                .json(ErrorResponse::new(SOLAR_DATE_RANGE_ERROR.to_string()));
+        }
+        Some(solar_date) => solar_date,
+    };
+    let midday = NaiveDateTime::new(solar_date, NaiveTime::from_hms_opt(12, 0, 0).unwrap());
+    let t = vncalendar::time::VNDate::new_with_calendar(midday.and_utc(), calendar);
+
+    let request_event_id = request
+        .extensions()
+        .get::<RequestEventId>()
+        .unwrap()
+        .clone();
+
+    HttpResponse::Ok().json(VNDateResponse::new_with_meta(
+        date_to_response(&t, &lang),
+        ResponseMeta::new(request_event_id),
+    ))
+}
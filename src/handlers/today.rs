@@ -7,6 +7,7 @@ use super::date_to_response;
 
 use crate::{
     models::RequestEventId,
+    requests::LanguageQuery,
     responses::{ResponseMeta, VNDateResponse},
     unleash::getunleash,
 };
@@ -17,12 +18,16 @@ use unleash_api_client::Context;
 #[utoipa::path(
     get,
     path = "/today",
+    params(LanguageQuery),
     responses(
         (status = 200, description = "Today in solar and lunar calendar", body = VNDateResponse),
     )
 )]
 #[get("/today")]
-pub async fn today_route(request: HttpRequest) -> HttpResponse {
+pub async fn today_route(
+    request: HttpRequest,
+    lang: actix_web::web::Query<LanguageQuery>,
+) -> HttpResponse {
     let mut properties = HashMap::new();
     properties.insert("user_agent".to_string(), "Chrome".to_string());
 
@@ -45,8 +50,10 @@ pub async fn today_route(request: HttpRequest) -> HttpResponse {
 
     let t = vncalendar::time::VNDate::today();
 
-    let response =
-        VNDateResponse::new_with_meta(date_to_response(&t), ResponseMeta::new(request_event_id));
+    let response = VNDateResponse::new_with_meta(
+        date_to_response(&t, &lang),
+        ResponseMeta::new(request_event_id),
+    );
 
     HttpResponse::Ok().json(response)
 }
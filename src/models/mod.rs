@@ -8,6 +8,9 @@ pub struct VNDate {
     lunar: String,
     solar: String,
     is_leap: bool,
+    // Vietnamese text of the date, with lang=vi
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
 }
 
 impl VNDate {
@@ -16,6 +19,227 @@ impl VNDate {
             lunar: lunar,
             solar: solar,
             is_leap: is_leap,
+            text: None,
+        }
+    }
+
+    pub fn with_text(self, text: Option<String>) -> Self {
+        Self { text, ..self }
+    }
+}
+
+#[derive(ToSchema, Serialize)]
+pub struct Holiday {
+    id: String,
+    name_vi: String,
+    name_en: String,
+    lunar: String,
+    solar: String,
+    is_leap: bool,
+    is_day_off: bool,
+}
+
+impl Holiday {
+    pub fn new(
+        id: String,
+        name_vi: String,
+        name_en: String,
+        lunar: String,
+        solar: String,
+        is_leap: bool,
+        is_day_off: bool,
+    ) -> Self {
+        Self {
+            id,
+            name_vi,
+            name_en,
+            lunar,
+            solar,
+            is_leap,
+            is_day_off,
+        }
+    }
+}
+
+#[derive(ToSchema, Serialize)]
+pub struct MoonPhase {
+    // new_moon, first_quarter, full_moon or last_quarter
+    phase: String,
+    name: String,
+    // RFC 3339 in Vietnamese time
+    time: String,
+}
+
+impl MoonPhase {
+    pub fn new(phase: String, name: String, time: String) -> Self {
+        Self { phase, name, time }
+    }
+}
+
+#[derive(ToSchema, Serialize)]
+pub struct MoonDay {
+    solar: String,
+    // Fraction of the lunation at midday in Vietnam, 0 new moon, 0.5 full moon
+    phase: f64,
+    // Illuminated fraction of the disk at midday in Vietnam
+    illumination: f64,
+}
+
+impl MoonDay {
+    pub fn new(solar: String, phase: f64, illumination: f64) -> Self {
+        Self {
+            solar,
+            phase,
+            illumination,
+        }
+    }
+}
+
+#[derive(ToSchema, Serialize)]
+pub struct Moon {
+    phases: Vec<MoonPhase>,
+    days: Vec<MoonDay>,
+}
+
+impl Moon {
+    pub fn new(phases: Vec<MoonPhase>, days: Vec<MoonDay>) -> Self {
+        Self { phases, days }
+    }
+}
+
+#[derive(ToSchema, Serialize)]
+pub struct DayGod {
+    // thanh_long, minh_duong, ...
+    id: String,
+    name: String,
+    // Hoàng đạo
+    is_auspicious: bool,
+}
+
+impl DayGod {
+    pub fn new(id: String, name: String, is_auspicious: bool) -> Self {
+        Self {
+            id,
+            name,
+            is_auspicious,
+        }
+    }
+}
+
+#[derive(ToSchema, Serialize)]
+pub struct LuckyHour {
+    // Earthly Branch of the hour, e.g. Tý
+    branch: String,
+    god: DayGod,
+    // hh:mm, Tý starts at 23:00 of the previous day
+    start: String,
+    end: String,
+}
+
+impl LuckyHour {
+    pub fn new(branch: String, god: DayGod, start: String, end: String) -> Self {
+        Self {
+            branch,
+            god,
+            start,
+            end,
+        }
+    }
+}
+
+#[derive(ToSchema, Serialize)]
+pub struct Almanac {
+    solar: String,
+    lunar: String,
+    is_leap: bool,
+    day_can_chi: String,
+    day_god: DayGod,
+    // Ngày hoàng đạo
+    is_auspicious: bool,
+    // The six giờ hoàng đạo
+    lucky_hours: Vec<LuckyHour>,
+    // One of the 12 Trực, e.g. Kiến
+    truc: String,
+    // One of the 28 mansions (Tú), e.g. Giác
+    mansion: String,
+}
+
+impl Almanac {
+    pub fn new(
+        solar: String,
+        lunar: String,
+        is_leap: bool,
+        day_can_chi: String,
+        day_god: DayGod,
+        lucky_hours: Vec<LuckyHour>,
+        truc: String,
+        mansion: String,
+    ) -> Self {
+        let is_auspicious = day_god.is_auspicious;
+        Self {
+            solar,
+            lunar,
+            is_leap,
+            day_can_chi,
+            day_god,
+            is_auspicious,
+            lucky_hours,
+            truc,
+            mansion,
+        }
+    }
+}
+
+#[derive(ToSchema, Serialize)]
+pub struct Pillar {
+    can_chi: String,
+    // Hán characters, e.g. 甲辰
+    han: String,
+    // e.g. Phú Đăng Hỏa
+    nap_am: String,
+    // Kim, Mộc, Thủy, Hỏa or Thổ
+    element: String,
+}
+
+impl Pillar {
+    pub fn new(can_chi: String, han: String, nap_am: String, element: String) -> Self {
+        Self {
+            can_chi,
+            han,
+            nap_am,
+            element,
+        }
+    }
+}
+
+#[derive(ToSchema, Serialize)]
+pub struct FourPillars {
+    // RFC 3339, as given
+    birth: String,
+    // Local time of the day and hour pillars, true solar time with a longitude
+    solar_time: String,
+    year: Pillar,
+    month: Pillar,
+    day: Pillar,
+    hour: Pillar,
+}
+
+impl FourPillars {
+    pub fn new(
+        birth: String,
+        solar_time: String,
+        year: Pillar,
+        month: Pillar,
+        day: Pillar,
+        hour: Pillar,
+    ) -> Self {
+        Self {
+            birth,
+            solar_time,
+            year,
+            month,
+            day,
+            hour,
         }
     }
 }
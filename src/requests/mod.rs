@@ -1,5 +1,31 @@
 use serde::Deserialize;
-use utoipa::IntoParams;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Deserialize, ToSchema, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum CalendarSystem {
+    Vietnamese,
+    Chinese,
+    Korean,
+}
+
+#[derive(Deserialize, ToSchema, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    En,
+    Vi,
+}
+
+#[derive(IntoParams, Deserialize)]
+#[into_params(parameter_in = Query)]
+pub struct LanguageQuery {
+    // vi adds the dates as Vietnamese text
+    #[param(inline)]
+    pub lang: Option<Language>,
+    // Hán-Nôm characters of the year in the text
+    #[param()]
+    pub han_nom: Option<bool>,
+}
 
 #[derive(IntoParams, Deserialize)]
 #[into_params(parameter_in = Query)]
@@ -7,6 +33,16 @@ pub struct SolarToLunar {
     // Assume 4 digits year
     #[param(max_length = 10)]
     pub solar_date: String,
+    // Lunisolar calendar of the lunar date, defaults to vietnamese
+    #[param(inline)]
+    pub calendar: Option<CalendarSystem>,
+}
+
+#[derive(Deserialize, ToSchema, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum DatesCalendar {
+    Solar,
+    Lunar,
 }
 
 #[derive(Deserialize, IntoParams)]
@@ -16,4 +52,96 @@ pub struct SolarToLunarDates {
     pub year: i32,
     #[param()]
     pub month: Option<u8>,
+    // Calendar of year and month, defaults to solar
+    #[param(inline)]
+    pub calendar: Option<DatesCalendar>,
+    // Leap lunar month, only used with lunar calendar
+    #[param()]
+    pub is_leap: Option<bool>,
+}
+
+#[derive(IntoParams, Deserialize)]
+#[into_params(parameter_in = Query)]
+pub struct LunarToSolar {
+    // yyyy-mm-dd with an optional L, dd/mm/yyyy or Vietnamese text such as
+    // "rằm tháng Giêng", a date without year is in the current lunar year
+    #[param()]
+    pub lunar_date: String,
+    // Leap lunar month, same as an L or "nhuận" in lunar_date, which false contradicts
+    #[param()]
+    pub is_leap: Option<bool>,
+    // Lunisolar calendar of the lunar date, defaults to vietnamese
+    #[param(inline)]
+    pub calendar: Option<CalendarSystem>,
+}
+
+#[derive(Deserialize, ToSchema, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum HolidayKind {
+    // Civic holidays set by the state
+    Official,
+    // Festivals of the lunar calendar, Tết and Giỗ Tổ Hùng Vương are days off too
+    Traditional,
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct HolidaysQuery {
+    #[param()]
+    pub year: i32,
+    #[param()]
+    pub month: Option<u8>,
+    #[param(inline)]
+    pub kind: Option<HolidayKind>,
+}
+
+#[derive(Deserialize, ToSchema, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum CalendarFeed {
+    // One event per day with the lunar date
+    Lunar,
+    Holidays,
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CalendarFeedQuery {
+    #[param()]
+    pub from: i32,
+    // Defaults to from
+    #[param()]
+    pub to: Option<i32>,
+    // Defaults to lunar
+    #[param(inline)]
+    pub feed: Option<CalendarFeed>,
+}
+
+#[derive(IntoParams, Deserialize)]
+#[into_params(parameter_in = Query)]
+pub struct AlmanacQuery {
+    // Assume 4 digits year
+    #[param(max_length = 10)]
+    pub solar_date: String,
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct MoonQuery {
+    // yyyy-mm-dd
+    #[param(max_length = 10)]
+    pub from: String,
+    // yyyy-mm-dd, inclusive
+    #[param(max_length = 10)]
+    pub to: String,
+}
+
+#[derive(IntoParams, Deserialize)]
+#[into_params(parameter_in = Query)]
+pub struct FourPillarsQuery {
+    // RFC 3339 with offset, e.g. 2024-02-04T16:00:00+07:00
+    #[param(max_length = 35)]
+    pub birth: String,
+    // Longitude of the birthplace in degrees east, for true solar time
+    #[param(minimum = -180, maximum = 180)]
+    pub longitude: Option<f64>,
 }
@@ -4,7 +4,7 @@ use serde::Serialize;
 use utoipa::{ToResponse, ToSchema};
 use uuid::Uuid;
 
-use crate::models::{RequestEventId, VNDate};
+use crate::models::{Almanac, FourPillars, Holiday, Moon, RequestEventId, VNDate};
 
 #[derive(ToResponse, ToSchema, Serialize)]
 pub struct ResponseMeta {
@@ -19,7 +19,7 @@ impl ResponseMeta {
     }
 }
 
-#[derive(Serialize)]
+#[derive(ToSchema, Serialize)]
 pub struct ErrorResponse {
     message: String,
 }
@@ -72,3 +72,51 @@ impl YearMonthDatesResponse {
         Self { data, meta }
     }
 }
+
+#[derive(ToResponse, ToSchema, Serialize)]
+pub struct HolidaysResponse {
+    meta: ResponseMeta,
+    data: Vec<Holiday>,
+}
+
+impl HolidaysResponse {
+    pub fn new(data: Vec<Holiday>, meta: ResponseMeta) -> Self {
+        Self { data, meta }
+    }
+}
+
+#[derive(ToResponse, ToSchema, Serialize)]
+pub struct MoonResponse {
+    meta: ResponseMeta,
+    data: Moon,
+}
+
+impl MoonResponse {
+    pub fn new(data: Moon, meta: ResponseMeta) -> Self {
+        Self { data, meta }
+    }
+}
+
+#[derive(ToResponse, ToSchema, Serialize)]
+pub struct AlmanacResponse {
+    meta: ResponseMeta,
+    data: Almanac,
+}
+
+impl AlmanacResponse {
+    pub fn new(data: Almanac, meta: ResponseMeta) -> Self {
+        Self { data, meta }
+    }
+}
+
+#[derive(ToResponse, ToSchema, Serialize)]
+pub struct FourPillarsResponse {
+    meta: ResponseMeta,
+    data: FourPillars,
+}
+
+impl FourPillarsResponse {
+    pub fn new(data: FourPillars, meta: ResponseMeta) -> Self {
+        Self { data, meta }
+    }
+}
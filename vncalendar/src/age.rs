@@ -0,0 +1,262 @@
+extern crate amlich;
+
+use std::fmt;
+
+use amlich::{CanChi, EarthlyBranch};
+
+use super::time::VNDate;
+
+/// Con giáp of the branch as said in Vietnam, with Mèo for Mão and Trâu for Sửu
+pub fn con_giap(branch: EarthlyBranch) -> &'static str {
+    match branch {
+        EarthlyBranch::Ty => "Chuột",
+        EarthlyBranch::Suu => "Trâu",
+        EarthlyBranch::Dan => "Hổ",
+        EarthlyBranch::Mao => "Mèo",
+        EarthlyBranch::Thin => "Rồng",
+        EarthlyBranch::Ti => "Rắn",
+        EarthlyBranch::Ngo => "Ngựa",
+        EarthlyBranch::Mui => "Dê",
+        EarthlyBranch::Than => "Khỉ",
+        EarthlyBranch::Dau => "Gà",
+        EarthlyBranch::Tuat => "Chó",
+        EarthlyBranch::Hoi => "Lợn",
+    }
+}
+
+/// Tuổi mụ in the given lunar year: 1 in the year of birth, one more at every Tết
+#[inline]
+pub fn lunar_age(birth_lunar_year: i32, lunar_year: i32) -> i32 {
+    lunar_year - birth_lunar_year + 1
+}
+
+/// Whom a Kim Lâu age threatens when marrying or building a house
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum KimLau {
+    /// Kim Lâu Thân, oneself
+    Than,
+    /// Kim Lâu Thê, the spouse
+    The,
+    /// Kim Lâu Tử, the children
+    Tu,
+    /// Kim Lâu Lục Súc, the livestock
+    LucSuc,
+}
+
+impl KimLau {
+    pub fn name(&self) -> &'static str {
+        match self {
+            KimLau::Than => "Kim Lâu Thân",
+            KimLau::The => "Kim Lâu Thê",
+            KimLau::Tu => "Kim Lâu Tử",
+            KimLau::LucSuc => "Kim Lâu Lục Súc",
+        }
+    }
+}
+
+impl fmt::Display for KimLau {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Kim Lâu of a lunar age, when it leaves 1, 3, 6 or 8 divided by 9
+pub fn kim_lau(lunar_age: i32) -> Option<KimLau> {
+    match lunar_age.rem_euclid(9) {
+        1 => Some(KimLau::Than),
+        3 => Some(KimLau::The),
+        6 => Some(KimLau::Tu),
+        8 => Some(KimLau::LucSuc),
+        _ => None,
+    }
+}
+
+/// The six Hoang Ốc palaces counted on the hand for house building
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HoangOc {
+    NhatCat = 0,
+    NhiNghi = 1,
+    TamDiaSat = 2,
+    TuTanTai = 3,
+    NguThoTu = 4,
+    LucHoangOc = 5,
+}
+
+pub const HOANG_OCS: [HoangOc; 6] = [
+    HoangOc::NhatCat,
+    HoangOc::NhiNghi,
+    HoangOc::TamDiaSat,
+    HoangOc::TuTanTai,
+    HoangOc::NguThoTu,
+    HoangOc::LucHoangOc,
+];
+
+impl HoangOc {
+    #[inline]
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            HoangOc::NhatCat => "Nhất Cát",
+            HoangOc::NhiNghi => "Nhì Nghi",
+            HoangOc::TamDiaSat => "Tam Địa Sát",
+            HoangOc::TuTanTai => "Tứ Tấn Tài",
+            HoangOc::NguThoTu => "Ngũ Thọ Tử",
+            HoangOc::LucHoangOc => "Lục Hoang Ốc",
+        }
+    }
+
+    /// Tam Địa Sát, Ngũ Thọ Tử and Lục Hoang Ốc forbid building
+    pub fn is_bad(&self) -> bool {
+        matches!(
+            self,
+            HoangOc::TamDiaSat | HoangOc::NguThoTu | HoangOc::LucHoangOc
+        )
+    }
+}
+
+impl fmt::Display for HoangOc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Palace of a lunar age. Every ten years start one palace further from Nhất Cát
+/// at 10, then the years past the ten move on one palace each.
+pub fn hoang_oc(lunar_age: i32) -> HoangOc {
+    let position = lunar_age / 10 - 1 + lunar_age % 10;
+    HOANG_OCS[position.rem_euclid(6) as usize]
+}
+
+/// Tam Tai, the three bad years in a row every twelve for the branch of the birth
+/// year: Dần, Mão, Thìn for Thân, Tý, Thìn and so on for the other triads
+pub fn is_tam_tai(birth_branch: EarthlyBranch, year_branch: EarthlyBranch) -> bool {
+    let first = 2 - 3 * (birth_branch.index() as i64 % 4);
+    (year_branch.index() as i64 - first).rem_euclid(12) < 3
+}
+
+/// Age and the yearly checks of a person in a given lunar year
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Age {
+    pub birth_year: CanChi,
+    pub lunar_age: i32,
+    /// Năm tuổi, the year has the branch of the birth year
+    pub is_birth_branch_year: bool,
+    pub kim_lau: Option<KimLau>,
+    pub hoang_oc: HoangOc,
+    pub is_tam_tai: bool,
+}
+
+impl Age {
+    pub fn new(birth_lunar_year: i32, lunar_year: i32) -> Self {
+        let birth_year = amlich::canchi::year_can_chi(birth_lunar_year);
+        let year = amlich::canchi::year_can_chi(lunar_year);
+        let lunar_age = lunar_age(birth_lunar_year, lunar_year);
+
+        Self {
+            birth_year,
+            lunar_age,
+            is_birth_branch_year: birth_year.branch == year.branch,
+            kim_lau: kim_lau(lunar_age),
+            hoang_oc: hoang_oc(lunar_age),
+            is_tam_tai: is_tam_tai(birth_year.branch, year.branch),
+        }
+    }
+
+    /// Con giáp of the birth year
+    #[inline]
+    pub fn con_giap(&self) -> &'static str {
+        con_giap(self.birth_year.branch)
+    }
+}
+
+/// Age of someone born on the given date at another date, counted in lunar years,
+/// None before the birth
+pub fn age(birth: &VNDate, on: &VNDate) -> Option<Age> {
+    match on < birth {
+        true => None,
+        false => Some(Age::new(birth.year(), on.year())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lunar_age_test() {
+        // born on 29 tháng Chạp Quý Mão, two the next day
+        let birth = VNDate::from_solar_date(2024, 2, 9).unwrap();
+        assert_eq!(1, age(&birth, &birth).unwrap().lunar_age);
+        let tet = VNDate::from_solar_date(2024, 2, 10).unwrap();
+        assert_eq!(2, age(&birth, &tet).unwrap().lunar_age);
+        assert_eq!(None, age(&tet, &birth));
+
+        let birth = VNDate::from_solar_date(1990, 5, 1).unwrap();
+        let on = VNDate::from_solar_date(2024, 6, 1).unwrap();
+        let age = age(&birth, &on).unwrap();
+        assert_eq!(35, age.lunar_age);
+        assert_eq!("Canh Ngọ", age.birth_year.to_string());
+        assert_eq!("Ngựa", age.con_giap());
+        assert!(!age.is_birth_branch_year);
+        assert_eq!(Some(KimLau::LucSuc), age.kim_lau);
+        assert_eq!(HoangOc::NhiNghi, age.hoang_oc);
+        assert!(!age.is_tam_tai);
+    }
+
+    #[test]
+    fn con_giap_test() {
+        assert_eq!("Mèo", con_giap(EarthlyBranch::Mao));
+        assert_eq!("Trâu", con_giap(EarthlyBranch::Suu));
+        assert_eq!("Lợn", con_giap(EarthlyBranch::Hoi));
+    }
+
+    #[test]
+    fn kim_lau_test() {
+        let ages: Vec<i32> = (18..=30).filter(|age| kim_lau(*age).is_some()).collect();
+        assert_eq!(vec![19, 21, 24, 26, 28, 30], ages);
+        assert_eq!(Some(KimLau::Than), kim_lau(28));
+        assert_eq!(Some(KimLau::The), kim_lau(21));
+        assert_eq!(None, kim_lau(27));
+    }
+
+    #[test]
+    fn hoang_oc_test() {
+        assert_eq!(HoangOc::NhatCat, hoang_oc(10));
+        assert_eq!(HoangOc::NhiNghi, hoang_oc(11));
+        assert_eq!(HoangOc::NhiNghi, hoang_oc(20));
+        assert_eq!(HoangOc::NhatCat, hoang_oc(70));
+        let bad: Vec<i32> = (10..=33).filter(|age| hoang_oc(*age).is_bad()).collect();
+        assert_eq!(vec![12, 14, 15, 18, 21, 23, 24, 27, 29, 30, 32, 33], bad);
+    }
+
+    #[test]
+    fn tam_tai_test() {
+        let years = |birth| -> Vec<&'static str> {
+            amlich::canchi::EARTHLY_BRANCHES
+                .iter()
+                .filter(|year| is_tam_tai(birth, **year))
+                .map(|year| year.name())
+                .collect()
+        };
+        assert_eq!(vec!["Dần", "Mão", "Thìn"], years(EarthlyBranch::Than));
+        assert_eq!(vec!["Thân", "Dậu", "Tuất"], years(EarthlyBranch::Ngo));
+        assert_eq!(vec!["Tỵ", "Ngọ", "Mùi"], years(EarthlyBranch::Mao));
+        assert_eq!(vec!["Tý", "Sửu", "Hợi"], years(EarthlyBranch::Dau));
+
+        // Canh Ngọ meets Tam Tai in 2028, 2029 and 2030
+        assert!(!Age::new(1990, 2027).is_tam_tai);
+        assert!(Age::new(1990, 2028).is_tam_tai);
+        assert!(Age::new(1990, 2030).is_tam_tai);
+        assert!(!Age::new(1990, 2031).is_tam_tai);
+    }
+
+    #[test]
+    fn birth_branch_year_test() {
+        assert!(Age::new(1990, 2026).is_birth_branch_year);
+        assert_eq!(37, Age::new(1990, 2026).lunar_age);
+        assert!(!Age::new(1990, 2025).is_birth_branch_year);
+    }
+}
@@ -0,0 +1,549 @@
+extern crate amlich;
+
+use std::fmt;
+
+use amlich::{CanChi, EarthlyBranch, JulianDay, SolarTerm};
+
+use super::time::VNDate;
+
+/// The twelve gods ruling days and hours in turn. Six are hoàng đạo
+/// (auspicious), the other six hắc đạo.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DayGod {
+    ThanhLong = 0,
+    MinhDuong = 1,
+    ThienHinh = 2,
+    ChuTuoc = 3,
+    KimQuy = 4,
+    ThienDuc = 5,
+    BachHo = 6,
+    NgocDuong = 7,
+    ThienLao = 8,
+    NguyenVu = 9,
+    TuMenh = 10,
+    CauTran = 11,
+}
+
+pub const DAY_GODS: [DayGod; 12] = [
+    DayGod::ThanhLong,
+    DayGod::MinhDuong,
+    DayGod::ThienHinh,
+    DayGod::ChuTuoc,
+    DayGod::KimQuy,
+    DayGod::ThienDuc,
+    DayGod::BachHo,
+    DayGod::NgocDuong,
+    DayGod::ThienLao,
+    DayGod::NguyenVu,
+    DayGod::TuMenh,
+    DayGod::CauTran,
+];
+
+impl DayGod {
+    /// God at the given position of the cycle starting from Thanh Long,
+    /// wraps around for any value
+    pub fn from_index(index: i64) -> Self {
+        DAY_GODS[index.rem_euclid(12) as usize]
+    }
+
+    #[inline]
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            DayGod::ThanhLong => "thanh_long",
+            DayGod::MinhDuong => "minh_duong",
+            DayGod::ThienHinh => "thien_hinh",
+            DayGod::ChuTuoc => "chu_tuoc",
+            DayGod::KimQuy => "kim_quy",
+            DayGod::ThienDuc => "thien_duc",
+            DayGod::BachHo => "bach_ho",
+            DayGod::NgocDuong => "ngoc_duong",
+            DayGod::ThienLao => "thien_lao",
+            DayGod::NguyenVu => "nguyen_vu",
+            DayGod::TuMenh => "tu_menh",
+            DayGod::CauTran => "cau_tran",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DayGod::ThanhLong => "Thanh Long",
+            DayGod::MinhDuong => "Minh Đường",
+            DayGod::ThienHinh => "Thiên Hình",
+            DayGod::ChuTuoc => "Chu Tước",
+            DayGod::KimQuy => "Kim Quỹ",
+            DayGod::ThienDuc => "Thiên Đức",
+            DayGod::BachHo => "Bạch Hổ",
+            DayGod::NgocDuong => "Ngọc Đường",
+            DayGod::ThienLao => "Thiên Lao",
+            DayGod::NguyenVu => "Nguyên Vũ",
+            DayGod::TuMenh => "Tư Mệnh",
+            DayGod::CauTran => "Câu Trần",
+        }
+    }
+
+    /// Hoàng đạo, as opposed to hắc đạo
+    pub fn is_auspicious(&self) -> bool {
+        matches!(
+            self,
+            DayGod::ThanhLong
+                | DayGod::MinhDuong
+                | DayGod::KimQuy
+                | DayGod::ThienDuc
+                | DayGod::NgocDuong
+                | DayGod::TuMenh
+        )
+    }
+}
+
+impl fmt::Display for DayGod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// God of the day. Thanh Long rules the Tý day in months 1 and 7 and moves
+/// two branches on every month after.
+pub fn day_god(lunar_month: u32, day_branch: EarthlyBranch) -> DayGod {
+    let thanh_long = ((lunar_month as i64 - 1) % 6) * 2;
+    DayGod::from_index(day_branch.index() as i64 - thanh_long)
+}
+
+/// God of the hour. Thanh Long rules the Thân hour on Tý and Ngọ days and
+/// moves two branches on every day after.
+pub fn hour_god(day_branch: EarthlyBranch, hour_branch: EarthlyBranch) -> DayGod {
+    let thanh_long = 8 + (day_branch.index() as i64 % 6) * 2;
+    DayGod::from_index(hour_branch.index() as i64 - thanh_long)
+}
+
+/// A two-hour block of the day
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Hour {
+    pub branch: EarthlyBranch,
+    pub god: DayGod,
+    /// Clock hour the block starts at, 23 for Tý
+    pub start_hour: u32,
+    /// Clock hour the block ends at, excluded
+    pub end_hour: u32,
+}
+
+impl Hour {
+    fn new(day_branch: EarthlyBranch, branch: EarthlyBranch) -> Self {
+        let start_hour = (branch.index() as u32 * 2 + 23) % 24;
+        Self {
+            branch,
+            god: hour_god(day_branch, branch),
+            start_hour,
+            end_hour: (start_hour + 2) % 24,
+        }
+    }
+}
+
+/// The six giờ hoàng đạo of a day with the given branch, from Tý on
+pub fn lucky_hours(day_branch: EarthlyBranch) -> Vec<Hour> {
+    amlich::canchi::EARTHLY_BRANCHES
+        .iter()
+        .map(|branch| Hour::new(day_branch, *branch))
+        .filter(|hour| hour.god.is_auspicious())
+        .collect()
+}
+
+/// The twelve day officers (thập nhị trực), from Kiến to Bế
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Truc {
+    Kien = 0,
+    Tru = 1,
+    Man = 2,
+    Binh = 3,
+    Dinh = 4,
+    Chap = 5,
+    Pha = 6,
+    Nguy = 7,
+    Thanh = 8,
+    Thu = 9,
+    Khai = 10,
+    Be = 11,
+}
+
+pub const TRUCS: [Truc; 12] = [
+    Truc::Kien,
+    Truc::Tru,
+    Truc::Man,
+    Truc::Binh,
+    Truc::Dinh,
+    Truc::Chap,
+    Truc::Pha,
+    Truc::Nguy,
+    Truc::Thanh,
+    Truc::Thu,
+    Truc::Khai,
+    Truc::Be,
+];
+
+impl Truc {
+    /// Trực at the given position of the cycle starting from Kiến,
+    /// wraps around for any value
+    pub fn from_index(index: i64) -> Self {
+        TRUCS[index.rem_euclid(12) as usize]
+    }
+
+    #[inline]
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            Truc::Kien => "kien",
+            Truc::Tru => "tru",
+            Truc::Man => "man",
+            Truc::Binh => "binh",
+            Truc::Dinh => "dinh",
+            Truc::Chap => "chap",
+            Truc::Pha => "pha",
+            Truc::Nguy => "nguy",
+            Truc::Thanh => "thanh",
+            Truc::Thu => "thu",
+            Truc::Khai => "khai",
+            Truc::Be => "be",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Truc::Kien => "Kiến",
+            Truc::Tru => "Trừ",
+            Truc::Man => "Mãn",
+            Truc::Binh => "Bình",
+            Truc::Dinh => "Định",
+            Truc::Chap => "Chấp",
+            Truc::Pha => "Phá",
+            Truc::Nguy => "Nguy",
+            Truc::Thanh => "Thành",
+            Truc::Thu => "Thu",
+            Truc::Khai => "Khai",
+            Truc::Be => "Bế",
+        }
+    }
+}
+
+impl fmt::Display for Truc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Branch of the month counted by solar terms: the Dần month starts at
+/// Lập Xuân, the Mão month at Kinh Trập and so on, every other term
+pub fn solar_month_branch(term: SolarTerm) -> EarthlyBranch {
+    EarthlyBranch::from_index(2 + term.index() as i64 / 2)
+}
+
+/// Trực of the day. Kiến falls on the day whose branch is the branch of the
+/// solar month, so the Trực of the day before repeats on the first day of
+/// every solar month.
+pub fn truc(term: SolarTerm, day_branch: EarthlyBranch) -> Truc {
+    Truc::from_index(day_branch.index() as i64 - solar_month_branch(term).index() as i64)
+}
+
+/// The 28 lunar mansions (nhị thập bát tú), from Giác to Chẩn. Vĩ and Vị,
+/// Tỉnh and Tinh are told apart by the animal of their full name.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Mansion {
+    Giac = 0,
+    Cang = 1,
+    De = 2,
+    Phong = 3,
+    Tam = 4,
+    /// Vĩ
+    ViHo = 5,
+    Co = 6,
+    Dau = 7,
+    Nguu = 8,
+    Nu = 9,
+    Hu = 10,
+    Nguy = 11,
+    That = 12,
+    Bich = 13,
+    Khue = 14,
+    Lau = 15,
+    /// Vị
+    ViTri = 16,
+    Mao = 17,
+    Tat = 18,
+    Chuy = 19,
+    Sam = 20,
+    /// Tỉnh
+    TinhHan = 21,
+    Quy = 22,
+    Lieu = 23,
+    /// Tinh
+    TinhMa = 24,
+    Truong = 25,
+    Duc = 26,
+    Chan = 27,
+}
+
+pub const MANSIONS: [Mansion; 28] = [
+    Mansion::Giac,
+    Mansion::Cang,
+    Mansion::De,
+    Mansion::Phong,
+    Mansion::Tam,
+    Mansion::ViHo,
+    Mansion::Co,
+    Mansion::Dau,
+    Mansion::Nguu,
+    Mansion::Nu,
+    Mansion::Hu,
+    Mansion::Nguy,
+    Mansion::That,
+    Mansion::Bich,
+    Mansion::Khue,
+    Mansion::Lau,
+    Mansion::ViTri,
+    Mansion::Mao,
+    Mansion::Tat,
+    Mansion::Chuy,
+    Mansion::Sam,
+    Mansion::TinhHan,
+    Mansion::Quy,
+    Mansion::Lieu,
+    Mansion::TinhMa,
+    Mansion::Truong,
+    Mansion::Duc,
+    Mansion::Chan,
+];
+
+impl Mansion {
+    /// Mansion at the given position of the cycle starting from Giác,
+    /// wraps around for any value
+    pub fn from_index(index: i64) -> Self {
+        MANSIONS[index.rem_euclid(28) as usize]
+    }
+
+    #[inline]
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            Mansion::Giac => "giac",
+            Mansion::Cang => "cang",
+            Mansion::De => "de",
+            Mansion::Phong => "phong",
+            Mansion::Tam => "tam",
+            Mansion::ViHo => "vi_ho",
+            Mansion::Co => "co",
+            Mansion::Dau => "dau",
+            Mansion::Nguu => "nguu",
+            Mansion::Nu => "nu",
+            Mansion::Hu => "hu",
+            Mansion::Nguy => "nguy",
+            Mansion::That => "that",
+            Mansion::Bich => "bich",
+            Mansion::Khue => "khue",
+            Mansion::Lau => "lau",
+            Mansion::ViTri => "vi_tri",
+            Mansion::Mao => "mao",
+            Mansion::Tat => "tat",
+            Mansion::Chuy => "chuy",
+            Mansion::Sam => "sam",
+            Mansion::TinhHan => "tinh_han",
+            Mansion::Quy => "quy",
+            Mansion::Lieu => "lieu",
+            Mansion::TinhMa => "tinh_ma",
+            Mansion::Truong => "truong",
+            Mansion::Duc => "duc",
+            Mansion::Chan => "chan",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Mansion::Giac => "Giác",
+            Mansion::Cang => "Cang",
+            Mansion::De => "Đê",
+            Mansion::Phong => "Phòng",
+            Mansion::Tam => "Tâm",
+            Mansion::ViHo => "Vĩ",
+            Mansion::Co => "Cơ",
+            Mansion::Dau => "Đẩu",
+            Mansion::Nguu => "Ngưu",
+            Mansion::Nu => "Nữ",
+            Mansion::Hu => "Hư",
+            Mansion::Nguy => "Nguy",
+            Mansion::That => "Thất",
+            Mansion::Bich => "Bích",
+            Mansion::Khue => "Khuê",
+            Mansion::Lau => "Lâu",
+            Mansion::ViTri => "Vị",
+            Mansion::Mao => "Mão",
+            Mansion::Tat => "Tất",
+            Mansion::Chuy => "Chủy",
+            Mansion::Sam => "Sâm",
+            Mansion::TinhHan => "Tỉnh",
+            Mansion::Quy => "Quỷ",
+            Mansion::Lieu => "Liễu",
+            Mansion::TinhMa => "Tinh",
+            Mansion::Truong => "Trương",
+            Mansion::Duc => "Dực",
+            Mansion::Chan => "Chẩn",
+        }
+    }
+}
+
+impl fmt::Display for Mansion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Mansion of the day. The cycle runs on without a break, four weeks long,
+/// with Giác always on a Thursday.
+pub fn mansion(julian_day: JulianDay) -> Mansion {
+    Mansion::from_index(julian_day.value() + 11)
+}
+
+/// What printed almanacs tell about a day
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Almanac {
+    pub day_can_chi: CanChi,
+    pub day_god: DayGod,
+    pub lucky_hours: Vec<Hour>,
+    pub truc: Truc,
+    pub mansion: Mansion,
+}
+
+impl Almanac {
+    /// Ngày hoàng đạo
+    #[inline]
+    pub fn is_auspicious(&self) -> bool {
+        self.day_god.is_auspicious()
+    }
+}
+
+pub fn almanac(date: &VNDate) -> Almanac {
+    let day_can_chi = date.day_can_chi();
+
+    Almanac {
+        day_can_chi,
+        day_god: day_god(date.month(), day_can_chi.branch),
+        lucky_hours: lucky_hours(day_can_chi.branch),
+        truc: truc(date.solar_term(), day_can_chi.branch),
+        mansion: mansion(date.julian_day()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, Duration, Weekday};
+
+    use super::*;
+
+    fn branches(hours: &[Hour]) -> Vec<&'static str> {
+        hours.iter().map(|hour| hour.branch.name()).collect()
+    }
+
+    #[test]
+    fn day_god_test() {
+        let auspicious = |month| -> Vec<&'static str> {
+            amlich::canchi::EARTHLY_BRANCHES
+                .iter()
+                .filter(|branch| day_god(month, **branch).is_auspicious())
+                .map(|branch| branch.name())
+                .collect()
+        };
+        assert_eq!(
+            vec!["Tý", "Sửu", "Thìn", "Tỵ", "Mùi", "Tuất"],
+            auspicious(1)
+        );
+        assert_eq!(auspicious(1), auspicious(7));
+        assert_eq!(vec!["Tý", "Dần", "Mão", "Ngọ", "Mùi", "Dậu"], auspicious(2));
+        assert_eq!(
+            vec!["Dần", "Thìn", "Tỵ", "Thân", "Dậu", "Hợi"],
+            auspicious(3)
+        );
+        assert_eq!(DayGod::ThanhLong, day_god(12, EarthlyBranch::Tuat));
+        assert_eq!(DayGod::CauTran, day_god(12, EarthlyBranch::Dau));
+    }
+
+    #[test]
+    fn lucky_hours_test() {
+        let hours = lucky_hours(EarthlyBranch::Ty);
+        assert_eq!(
+            vec!["Tý", "Sửu", "Mão", "Ngọ", "Thân", "Dậu"],
+            branches(&hours)
+        );
+        assert_eq!((23, 1), (hours[0].start_hour, hours[0].end_hour));
+        assert_eq!((1, 3), (hours[1].start_hour, hours[1].end_hour));
+        assert_eq!(DayGod::KimQuy, hours[0].god);
+        assert_eq!(DayGod::ThanhLong, hours[4].god);
+
+        assert_eq!(
+            vec!["Dần", "Mão", "Tỵ", "Thân", "Tuất", "Hợi"],
+            branches(&lucky_hours(EarthlyBranch::Suu))
+        );
+        assert_eq!(
+            branches(&lucky_hours(EarthlyBranch::Ti)),
+            branches(&lucky_hours(EarthlyBranch::Hoi))
+        );
+    }
+
+    #[test]
+    fn truc_test() {
+        assert_eq!(EarthlyBranch::Dan, solar_month_branch(SolarTerm::LapXuan));
+        assert_eq!(EarthlyBranch::Dan, solar_month_branch(SolarTerm::VuThuy));
+        assert_eq!(EarthlyBranch::Ty, solar_month_branch(SolarTerm::DaiTuyet));
+        assert_eq!(EarthlyBranch::Suu, solar_month_branch(SolarTerm::DaiHan));
+        assert_eq!(Truc::Kien, truc(SolarTerm::LapXuan, EarthlyBranch::Dan));
+        assert_eq!(Truc::Be, truc(SolarTerm::LapXuan, EarthlyBranch::Suu));
+        assert_eq!(Truc::Kien, truc(SolarTerm::DongChi, EarthlyBranch::Ty));
+
+        // Lập Xuân 2024 starts on 4/2, which repeats the Trực of 3/2
+        let d = VNDate::from_solar_date(2024, 2, 3).unwrap();
+        assert_eq!(Truc::Thanh, d.truc());
+        let d = VNDate::from_solar_date(2024, 2, 4).unwrap();
+        assert_eq!(Truc::Thanh, d.truc());
+        let d = VNDate::from_solar_date(2024, 2, 5).unwrap();
+        assert_eq!(Truc::Thu, d.truc());
+    }
+
+    #[test]
+    fn mansion_test() {
+        assert_eq!(Mansion::Chan, Mansion::from_index(-1));
+        assert_eq!("Vĩ", Mansion::ViHo.name());
+        assert_eq!("vi_tri", Mansion::ViTri.id());
+
+        // Thursdays only ever fall on Giác, Đẩu, Khuê and Tỉnh
+        let mut d = VNDate::from_solar_date(2024, 1, 4).unwrap();
+        assert_eq!(Mansion::TinhHan, d.mansion());
+        for _ in 0..8 {
+            assert_eq!(Weekday::Thu, d.get_solar_datetime().weekday());
+            assert_eq!(0, d.mansion().index() % 7);
+            d = d.add(Duration::days(7));
+        }
+        let d = VNDate::from_solar_date(2024, 1, 11).unwrap();
+        assert_eq!(Mansion::Giac, d.mansion());
+    }
+
+    #[test]
+    fn almanac_test() {
+        // Tết Giáp Thìn, a Giáp Thìn day in month 1
+        let d = VNDate::from_solar_date(2024, 2, 10).unwrap();
+        let almanac = almanac(&d);
+        assert_eq!("Giáp Thìn", almanac.day_can_chi.to_string());
+        assert_eq!(DayGod::KimQuy, almanac.day_god);
+        assert!(almanac.is_auspicious());
+        assert_eq!(
+            vec!["Dần", "Thìn", "Tỵ", "Thân", "Dậu", "Hợi"],
+            branches(&almanac.lucky_hours)
+        );
+        assert_eq!(Truc::Man, almanac.truc);
+        assert_eq!(Mansion::De, almanac.mansion);
+    }
+}
@@ -0,0 +1,217 @@
+extern crate amlich;
+
+use amlich::{LunarDate, LunarDateError};
+use chrono::TimeDelta;
+
+use super::time::VNDate;
+
+/// What to do when the anniversary day (30) does not exist because the month has 29 days
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum MissingDayPolicy {
+    /// Observe on the last day of the month (29)
+    #[default]
+    LastDayOfMonth,
+    /// Observe on the first day of the following month
+    FirstDayOfNextMonth,
+    /// No occurrence in that year
+    Skip,
+}
+
+/// What to do when the anniversary is in a leap month that the year does not have
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum MissingLeapMonthPolicy {
+    /// Observe in the regular month with the same number
+    #[default]
+    RegularMonth,
+    /// No occurrence in that year
+    Skip,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub struct AnniversaryPolicy {
+    pub missing_day: MissingDayPolicy,
+    pub missing_leap_month: MissingLeapMonthPolicy,
+}
+
+/// A yearly recurring lunar date such as a đám giỗ
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LunarAnniversary {
+    pub month: u32,
+    pub day: u32,
+    pub is_leap: bool,
+}
+
+#[derive(Clone)]
+pub struct AnniversaryOccurrence {
+    pub lunar_year: i32,
+    pub date: VNDate,
+    /// The occurrence was moved by the policy
+    pub adjusted: bool,
+}
+
+impl LunarAnniversary {
+    pub fn new(month: u32, day: u32, is_leap: bool) -> Self {
+        Self {
+            month,
+            day,
+            is_leap,
+        }
+    }
+
+    /// The anniversary in the given lunar year, None if the policy skips that year.
+    /// Errors for dates which can never exist, e.g. month 13 or day 31, and for years
+    /// out of range.
+    pub fn occurrence(
+        &self,
+        lunar_year: i32,
+        policy: AnniversaryPolicy,
+    ) -> Result<Option<AnniversaryOccurrence>, LunarDateError> {
+        let mut adjusted = false;
+        let mut lunar_date = LunarDate::new(lunar_year, self.month, self.day, self.is_leap);
+
+        let mut result = VNDate::from_lunar_date(lunar_date);
+        if let Err(LunarDateError::NoSuchLeapMonth { .. }) = result {
+            if policy.missing_leap_month == MissingLeapMonthPolicy::Skip {
+                return Ok(None);
+            }
+            adjusted = true;
+            lunar_date.is_leap = false;
+            result = VNDate::from_lunar_date(lunar_date);
+        }
+
+        let date = match result {
+            Ok(date) => date,
+            Err(LunarDateError::DayOutOfRange {
+                day: 30,
+                days_in_month: 29,
+            }) => {
+                adjusted = true;
+                lunar_date.day = 29;
+                let last_day = VNDate::from_lunar_date(lunar_date)?;
+                match policy.missing_day {
+                    MissingDayPolicy::LastDayOfMonth => last_day,
+                    MissingDayPolicy::FirstDayOfNextMonth => last_day
+                        .checked_add_signed(TimeDelta::days(1))
+                        .ok_or(LunarDateError::YearOutOfRange(lunar_year))?,
+                    MissingDayPolicy::Skip => return Ok(None),
+                }
+            }
+            Err(error) => return Err(error),
+        };
+
+        Ok(Some(AnniversaryOccurrence {
+            lunar_year,
+            date,
+            adjusted,
+        }))
+    }
+
+    /// Occurrences for the lunar years from_year..=to_year, years skipped by the policy are left out
+    pub fn occurrences(
+        &self,
+        from_year: i32,
+        to_year: i32,
+        policy: AnniversaryPolicy,
+    ) -> Result<Vec<AnniversaryOccurrence>, LunarDateError> {
+        let mut occurrences = vec![];
+        for year in from_year..=to_year {
+            if let Some(occurrence) = self.occurrence(year, policy)? {
+                occurrences.push(occurrence);
+            }
+        }
+
+        Ok(occurrences)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solar(occurrence: &AnniversaryOccurrence) -> String {
+        occurrence
+            .date
+            .get_solar_datetime()
+            .format("%Y-%m-%d")
+            .to_string()
+    }
+
+    #[test]
+    fn occurrences_test() {
+        let anniversary = LunarAnniversary::new(7, 15, false);
+        let result = anniversary
+            .occurrences(2024, 2026, AnniversaryPolicy::default())
+            .unwrap();
+        assert_eq!(3, result.len());
+        assert_eq!("2024-08-18", solar(&result[0]));
+        assert_eq!("2025-09-06", solar(&result[1]));
+        assert_eq!(2025, result[1].lunar_year);
+        assert!(!result[1].adjusted);
+    }
+
+    #[test]
+    fn missing_day_policy_test() {
+        // 30 Chap: month 12 of 2023 has 30 days, month 12 of 2024 has 29 days
+        let anniversary = LunarAnniversary::new(12, 30, false);
+
+        let result = anniversary
+            .occurrences(2023, 2024, AnniversaryPolicy::default())
+            .unwrap();
+        assert_eq!(2, result.len());
+        assert_eq!("2024-02-09", solar(&result[0]));
+        assert!(!result[0].adjusted);
+        assert_eq!("2025-01-28", solar(&result[1]));
+        assert!(result[1].adjusted);
+
+        let policy = AnniversaryPolicy {
+            missing_day: MissingDayPolicy::FirstDayOfNextMonth,
+            ..Default::default()
+        };
+        let result = anniversary.occurrences(2024, 2024, policy).unwrap();
+        assert_eq!("2025-01-29", solar(&result[0]));
+        assert_eq!(1, result[0].date.day());
+
+        let policy = AnniversaryPolicy {
+            missing_day: MissingDayPolicy::Skip,
+            ..Default::default()
+        };
+        let result = anniversary.occurrences(2023, 2024, policy).unwrap();
+        assert_eq!(1, result.len());
+        assert_eq!(2023, result[0].lunar_year);
+    }
+
+    #[test]
+    fn missing_leap_month_policy_test() {
+        // Leap month 6 only exists in 2025
+        let anniversary = LunarAnniversary::new(6, 10, true);
+
+        let result = anniversary
+            .occurrences(2025, 2026, AnniversaryPolicy::default())
+            .unwrap();
+        assert_eq!(2, result.len());
+        assert!(result[0].date.is_leap());
+        assert!(!result[0].adjusted);
+        assert!(!result[1].date.is_leap());
+        assert!(result[1].adjusted);
+        assert_eq!(6, result[1].date.month());
+
+        let policy = AnniversaryPolicy {
+            missing_leap_month: MissingLeapMonthPolicy::Skip,
+            ..Default::default()
+        };
+        let result = anniversary.occurrences(2025, 2026, policy).unwrap();
+        assert_eq!(1, result.len());
+        assert_eq!(2025, result[0].lunar_year);
+    }
+
+    #[test]
+    fn invalid_anniversary_test() {
+        let anniversary = LunarAnniversary::new(13, 1, false);
+        assert_eq!(
+            Some(LunarDateError::MonthOutOfRange(13)),
+            anniversary
+                .occurrences(2024, 2025, AnniversaryPolicy::default())
+                .err()
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use std::{error::Error, fmt};
+
+use chrono::{Datelike, Weekday};
+
+use super::time::VNDate;
+
+/// Specifiers of `VNDate::format`.
+///
+/// | Specifier | Meaning | Example |
+/// |-----------|---------|---------|
+/// | `%y` | lunar year | `2006` |
+/// | `%m` | lunar month, 2 digits | `07` |
+/// | `%d` | lunar day, 2 digits | `20` |
+/// | `%L` | `L` in a leap month | `L` |
+/// | `%l` | `nhuận` in a leap month | `nhuận` |
+/// | `%N` | lunar month name | `Bảy` |
+/// | `%Y` | solar year | `2006` |
+/// | `%M` | solar month, 2 digits | `09` |
+/// | `%D` | solar day, 2 digits | `12` |
+/// | `%B` | solar month name | `September` |
+/// | `%b` | abbreviated solar month name | `Sep` |
+/// | `%A` | weekday | `Tuesday` |
+/// | `%a` | abbreviated weekday | `Tue` |
+/// | `%W` | weekday in Vietnamese | `Thứ Ba` |
+/// | `%Cy` | Can Chi of the year | `Bính Tuất` |
+/// | `%Cm` | Can Chi of the month | `Bính Thân` |
+/// | `%Cd` | Can Chi of the day | `Giáp Thìn` |
+/// | `%Ch` | Can Chi of the hour | `Bính Ngọ` |
+/// | `%Z` | zodiac animal of the year | `Dog` |
+/// | `%T` | solar term | `Bạch Lộ` |
+/// | `%%` | `%` | `%` |
+pub const SPECIFIERS: [&str; 21] = [
+    "%y", "%m", "%d", "%L", "%l", "%N", "%Y", "%M", "%D", "%B", "%b", "%A", "%a", "%W", "%Cy",
+    "%Cm", "%Cd", "%Ch", "%Z", "%T", "%%",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatError {
+    /// A `%` followed by something that is not in `SPECIFIERS`
+    UnknownSpecifier(String),
+    /// The format ends in the middle of a specifier
+    Incomplete,
+    /// The format has no specifier and is none of the legacy `yyyy-mm-dd` patterns
+    NoSpecifier,
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FormatError::UnknownSpecifier(specifier) => {
+                write!(f, "Unknown format specifier {specifier}")
+            }
+            FormatError::Incomplete => write!(f, "The format ends with an incomplete specifier"),
+            FormatError::NoSpecifier => write!(
+                f,
+                "Invalid date format, should be similar as yyyy-mm-dd or %y-%m-%d"
+            ),
+        }
+    }
+}
+
+impl Error for FormatError {}
+
+const LUNAR_MONTH_NAMES: [&str; 12] = [
+    "Giêng", "Hai", "Ba", "Tư", "Năm", "Sáu", "Bảy", "Tám", "Chín", "Mười", "Một", "Chạp",
+];
+
+const SOLAR_MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Name of the lunar month as spoken, Giêng for the 1st, Một for the 11th
+/// and Chạp for the 12th
+pub fn lunar_month_name(month: u32) -> &'static str {
+    LUNAR_MONTH_NAMES[(month as usize + 11) % 12]
+}
+
+pub fn vietnamese_weekday(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Thứ Hai",
+        Weekday::Tue => "Thứ Ba",
+        Weekday::Wed => "Thứ Tư",
+        Weekday::Thu => "Thứ Năm",
+        Weekday::Fri => "Thứ Sáu",
+        Weekday::Sat => "Thứ Bảy",
+        Weekday::Sun => "Chủ Nhật",
+    }
+}
+
+fn english_weekday(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+// The patterns accepted before specifiers were introduced
+fn legacy_pattern(pattern: &str) -> Option<String> {
+    for separator in ["-", "/"] {
+        let parts: Vec<&str> = pattern.split(separator).collect();
+        match parts[..] {
+            ["yyyy", "mm", "dd"] => return Some(["%y", "%m", "%d"].join(separator)),
+            ["dd", "mm", "yyyy"] => return Some(["%d", "%m", "%y"].join(separator)),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+pub(crate) fn format_date(date: &VNDate, pattern: &str) -> Result<String, FormatError> {
+    let pattern = legacy_pattern(pattern).unwrap_or(pattern.to_string());
+    if !pattern.contains('%') {
+        return Err(FormatError::NoSpecifier);
+    }
+    let solar_time = date.get_solar_datetime();
+    let weekday = solar_time.weekday();
+    let mut result = String::new();
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            result.push(c);
+            continue;
+        }
+        let specifier = chars.next().ok_or(FormatError::Incomplete)?;
+        match specifier {
+            'y' => result.push_str(&date.year().to_string()),
+            'm' => result.push_str(&format!("{:02}", date.month())),
+            'd' => result.push_str(&format!("{:02}", date.day())),
+            'L' if date.is_leap() => result.push('L'),
+            'l' if date.is_leap() => result.push_str("nhuận"),
+            'L' | 'l' => {}
+            'N' => result.push_str(lunar_month_name(date.month())),
+            'Y' => result.push_str(&date.solar_year().to_string()),
+            'M' => result.push_str(&format!("{:02}", date.solar_month())),
+            'D' => result.push_str(&format!("{:02}", date.solar_day())),
+            'B' => result.push_str(SOLAR_MONTH_NAMES[date.solar_month() as usize - 1]),
+            'b' => result.push_str(&SOLAR_MONTH_NAMES[date.solar_month() as usize - 1][..3]),
+            'A' => result.push_str(english_weekday(weekday)),
+            'a' => result.push_str(&english_weekday(weekday)[..3]),
+            'W' => result.push_str(vietnamese_weekday(weekday)),
+            'C' => {
+                let can_chi = match chars.next().ok_or(FormatError::Incomplete)? {
+                    'y' => date.year_can_chi(),
+                    'm' => date.month_can_chi(),
+                    'd' => date.day_can_chi(),
+                    'h' => date.hour_can_chi(),
+                    other => return Err(FormatError::UnknownSpecifier(format!("%C{other}"))),
+                };
+                result.push_str(&can_chi.to_string());
+            }
+            'Z' => result.push_str(date.zodiac_animal()),
+            'T' => result.push_str(date.solar_term().name()),
+            '%' => result.push('%'),
+            other => return Err(FormatError::UnknownSpecifier(format!("%{other}"))),
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lunar_month_name_test() {
+        assert_eq!("Giêng", lunar_month_name(1));
+        assert_eq!("Mười", lunar_month_name(10));
+        assert_eq!("Một", lunar_month_name(11));
+        assert_eq!("Chạp", lunar_month_name(12));
+    }
+
+    #[test]
+    fn format_date_test() {
+        let d = VNDate::from_solar_date(2006, 9, 12).unwrap();
+        assert_eq!(
+            Ok("2006-07-20L (12/09/2006)".to_string()),
+            format_date(&d, "%y-%m-%d%L (%D/%M/%Y)")
+        );
+        assert_eq!(
+            Ok("Tuesday, 12 September 2006 - Thứ Ba, 20 tháng Bảy nhuận".to_string()),
+            format_date(&d, "%A, %D %B %Y - %W, %d tháng %N %l")
+        );
+        assert_eq!(
+            Ok("Tue 12 Sep, năm Bính Tuất (Dog), tiết Bạch Lộ".to_string()),
+            format_date(&d, "%a %D %b, năm %Cy (%Z), tiết %T")
+        );
+        assert_eq!(
+            Ok("ngày Giáp Thìn tháng Bính Thân 100%".to_string()),
+            format_date(&d, "ngày %Cd tháng %Cm 100%%")
+        );
+
+        let d = VNDate::from_solar_date(2024, 2, 10).unwrap();
+        assert_eq!(Ok("2024-01-01".to_string()), format_date(&d, "%y-%m-%d%L"));
+        assert_eq!(Ok("Thứ Bảy".to_string()), format_date(&d, "%W%l"));
+    }
+
+    #[test]
+    fn format_error_test() {
+        let d = VNDate::from_solar_date(2024, 2, 10).unwrap();
+        assert_eq!(
+            Err(FormatError::UnknownSpecifier("%q".to_string())),
+            format_date(&d, "%y %q")
+        );
+        assert_eq!(
+            Err(FormatError::UnknownSpecifier("%Cx".to_string())),
+            format_date(&d, "%Cx")
+        );
+        assert_eq!(Err(FormatError::Incomplete), format_date(&d, "%y-%"));
+        assert_eq!(Err(FormatError::Incomplete), format_date(&d, "%C"));
+        assert_eq!(Err(FormatError::NoSpecifier), format_date(&d, "y-m-d"));
+    }
+}
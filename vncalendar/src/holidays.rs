@@ -0,0 +1,284 @@
+extern crate amlich;
+
+use serde::Serialize;
+
+use super::time::VNDate;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HolidayDate {
+    Solar {
+        month: u32,
+        day: u32,
+    },
+    Lunar {
+        month: u32,
+        day: u32,
+    },
+    /// Last day of the lunar year, 29 or 30 of month 12
+    LunarNewYearsEve,
+}
+
+/// Civic holidays set by the state, or festivals of the lunar calendar. Both can be
+/// days off, e.g. Tết and Giỗ Tổ Hùng Vương are traditional.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HolidayKind {
+    Official,
+    Traditional,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct HolidayDefinition {
+    pub id: &'static str,
+    pub name_vi: &'static str,
+    pub name_en: &'static str,
+    pub date: HolidayDate,
+    pub kind: HolidayKind,
+    pub is_day_off: bool,
+}
+
+pub const HOLIDAYS: [HolidayDefinition; 14] = [
+    HolidayDefinition {
+        id: "new_year",
+        name_vi: "Tết Dương lịch",
+        name_en: "New Year's Day",
+        date: HolidayDate::Solar { month: 1, day: 1 },
+        kind: HolidayKind::Official,
+        is_day_off: true,
+    },
+    HolidayDefinition {
+        id: "reunification_day",
+        name_vi: "Ngày Giải phóng miền Nam, thống nhất đất nước",
+        name_en: "Reunification Day",
+        date: HolidayDate::Solar { month: 4, day: 30 },
+        kind: HolidayKind::Official,
+        is_day_off: true,
+    },
+    HolidayDefinition {
+        id: "labour_day",
+        name_vi: "Ngày Quốc tế Lao động",
+        name_en: "International Labour Day",
+        date: HolidayDate::Solar { month: 5, day: 1 },
+        kind: HolidayKind::Official,
+        is_day_off: true,
+    },
+    HolidayDefinition {
+        id: "national_day",
+        name_vi: "Quốc khánh",
+        name_en: "National Day",
+        date: HolidayDate::Solar { month: 9, day: 2 },
+        kind: HolidayKind::Official,
+        is_day_off: true,
+    },
+    HolidayDefinition {
+        id: "giao_thua",
+        name_vi: "Giao thừa",
+        name_en: "Lunar New Year's Eve",
+        date: HolidayDate::LunarNewYearsEve,
+        kind: HolidayKind::Traditional,
+        is_day_off: true,
+    },
+    HolidayDefinition {
+        id: "tet_1",
+        name_vi: "Mùng 1 Tết Nguyên Đán",
+        name_en: "Lunar New Year (day 1)",
+        date: HolidayDate::Lunar { month: 1, day: 1 },
+        kind: HolidayKind::Traditional,
+        is_day_off: true,
+    },
+    HolidayDefinition {
+        id: "tet_2",
+        name_vi: "Mùng 2 Tết Nguyên Đán",
+        name_en: "Lunar New Year (day 2)",
+        date: HolidayDate::Lunar { month: 1, day: 2 },
+        kind: HolidayKind::Traditional,
+        is_day_off: true,
+    },
+    HolidayDefinition {
+        id: "tet_3",
+        name_vi: "Mùng 3 Tết Nguyên Đán",
+        name_en: "Lunar New Year (day 3)",
+        date: HolidayDate::Lunar { month: 1, day: 3 },
+        kind: HolidayKind::Traditional,
+        is_day_off: true,
+    },
+    HolidayDefinition {
+        id: "ram_thang_gieng",
+        name_vi: "Rằm tháng Giêng",
+        name_en: "First Full Moon Festival",
+        date: HolidayDate::Lunar { month: 1, day: 15 },
+        kind: HolidayKind::Traditional,
+        is_day_off: false,
+    },
+    HolidayDefinition {
+        id: "hung_kings",
+        name_vi: "Giỗ Tổ Hùng Vương",
+        name_en: "Hung Kings' Commemoration Day",
+        date: HolidayDate::Lunar { month: 3, day: 10 },
+        kind: HolidayKind::Traditional,
+        is_day_off: true,
+    },
+    HolidayDefinition {
+        id: "doan_ngo",
+        name_vi: "Tết Đoan Ngọ",
+        name_en: "Mid-Year Festival",
+        date: HolidayDate::Lunar { month: 5, day: 5 },
+        kind: HolidayKind::Traditional,
+        is_day_off: false,
+    },
+    HolidayDefinition {
+        id: "vu_lan",
+        name_vi: "Lễ Vu Lan",
+        name_en: "Ghost Festival",
+        date: HolidayDate::Lunar { month: 7, day: 15 },
+        kind: HolidayKind::Traditional,
+        is_day_off: false,
+    },
+    HolidayDefinition {
+        id: "trung_thu",
+        name_vi: "Tết Trung Thu",
+        name_en: "Mid-Autumn Festival",
+        date: HolidayDate::Lunar { month: 8, day: 15 },
+        kind: HolidayKind::Traditional,
+        is_day_off: false,
+    },
+    HolidayDefinition {
+        id: "ong_cong_ong_tao",
+        name_vi: "Ông Công Ông Táo",
+        name_en: "Kitchen Gods' Day",
+        date: HolidayDate::Lunar { month: 12, day: 23 },
+        kind: HolidayKind::Traditional,
+        is_day_off: false,
+    },
+];
+
+#[derive(Clone, Serialize)]
+pub struct Holiday {
+    pub id: &'static str,
+    pub name_vi: &'static str,
+    pub name_en: &'static str,
+    pub date: VNDate,
+    pub kind: HolidayKind,
+    pub is_day_off: bool,
+}
+
+impl HolidayDefinition {
+    /// The date of this holiday within the given lunar year for lunar holidays,
+    /// or solar year for solar holidays
+    pub fn resolve(&self, year: i32) -> Option<VNDate> {
+        match self.date {
+            HolidayDate::Solar { month, day } => VNDate::from_solar_date(year, month, day),
+            HolidayDate::Lunar { month, day } => {
+                VNDate::from_lunar_date(amlich::LunarDate::new(year, month, day, false)).ok()
+            }
+            HolidayDate::LunarNewYearsEve => {
+                let new_year =
+                    VNDate::from_lunar_date(amlich::LunarDate::new(year + 1, 1, 1, false)).ok()?;
+                new_year.checked_add_signed(chrono::TimeDelta::days(-1))
+            }
+        }
+    }
+
+    fn holiday_on(&self, date: VNDate) -> Holiday {
+        Holiday {
+            id: self.id,
+            name_vi: self.name_vi,
+            name_en: self.name_en,
+            date,
+            kind: self.kind,
+            is_day_off: self.is_day_off,
+        }
+    }
+}
+
+/// All holidays falling in the given solar year, ordered by date
+pub fn get_holidays(year: i32) -> Vec<Holiday> {
+    let mut holidays: Vec<Holiday> = vec![];
+
+    for definition in HOLIDAYS.iter() {
+        let candidates = match definition.date {
+            HolidayDate::Solar { .. } => vec![definition.resolve(year)],
+            // Lunar holidays late in the lunar year can fall in the next solar year
+            _ => vec![definition.resolve(year - 1), definition.resolve(year)],
+        };
+
+        for date in candidates.into_iter().flatten() {
+            if date.solar_year() == year {
+                holidays.push(definition.holiday_on(date));
+            }
+        }
+    }
+
+    holidays.sort_by_key(|holiday| holiday.date.get_solar_datetime());
+
+    holidays
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find<'a>(holidays: &'a [Holiday], id: &str) -> Vec<&'a Holiday> {
+        holidays.iter().filter(|h| h.id == id).collect()
+    }
+
+    #[test]
+    fn get_holidays_test() {
+        let holidays = get_holidays(2025);
+        assert_eq!(14, holidays.len());
+
+        let tet = find(&holidays, "tet_1");
+        assert_eq!(1, tet.len());
+        assert_eq!(
+            "2025-01-29",
+            tet[0]
+                .date
+                .get_solar_datetime()
+                .format("%Y-%m-%d")
+                .to_string()
+        );
+
+        // 2024 has a 29 days month 12
+        let giao_thua = find(&holidays, "giao_thua");
+        assert_eq!(1, giao_thua.len());
+        assert_eq!("2024-12-29", giao_thua[0].date.format(None).unwrap());
+        assert_eq!(28, giao_thua[0].date.solar_day());
+
+        let hung_kings = find(&holidays, "hung_kings");
+        assert_eq!(4, hung_kings[0].date.solar_month());
+        assert_eq!(7, hung_kings[0].date.solar_day());
+
+        let ong_tao = find(&holidays, "ong_cong_ong_tao");
+        assert_eq!(1, ong_tao.len());
+        assert_eq!(1, ong_tao[0].date.solar_month());
+        assert_eq!(22, ong_tao[0].date.solar_day());
+
+        let trung_thu = find(&holidays, "trung_thu");
+        assert_eq!(10, trung_thu[0].date.solar_month());
+        assert_eq!(6, trung_thu[0].date.solar_day());
+        assert!(!trung_thu[0].is_day_off);
+        assert_eq!(HolidayKind::Traditional, trung_thu[0].kind);
+
+        // traditional holidays can be days off too
+        assert_eq!(HolidayKind::Traditional, tet[0].kind);
+        assert!(tet[0].is_day_off);
+        assert_eq!(HolidayKind::Traditional, hung_kings[0].kind);
+        assert!(hung_kings[0].is_day_off);
+        let national_day = find(&holidays, "national_day");
+        assert_eq!(HolidayKind::Official, national_day[0].kind);
+
+        for pair in holidays.windows(2) {
+            assert!(pair[0].date.get_solar_datetime() <= pair[1].date.get_solar_datetime());
+        }
+    }
+
+    #[test]
+    fn get_holidays_lunar_year_overlap_test() {
+        // 23 Chap of lunar 2023 is 2024-02-02, the one of lunar 2024 is 2025-01-22
+        let holidays = get_holidays(2024);
+        let ong_tao = find(&holidays, "ong_cong_ong_tao");
+        assert_eq!(1, ong_tao.len());
+        assert_eq!(2, ong_tao[0].date.solar_month());
+        assert_eq!(2, ong_tao[0].date.solar_day());
+    }
+}
@@ -0,0 +1,221 @@
+use chrono::{DateTime, Days, NaiveDate, Utc};
+
+use super::{get_month_dates, holidays::get_holidays, time::VNDate, MONTHS};
+
+const PRODUCT_ID: &str = "-//ramlich//vncalendar//VI";
+// RFC 5545 3.1, lines should not be longer than 75 octets excluding the line break
+const MAX_LINE_OCTETS: usize = 75;
+
+struct Event {
+    uid: String,
+    date: NaiveDate,
+    summary: String,
+    description: Option<String>,
+    transparent: bool,
+}
+
+// RFC 5545 3.3.11, escape TEXT values
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+// RFC 5545 3.1, fold long lines without splitting a multi-byte character
+fn write_line(output: &mut String, line: &str) {
+    let mut octets = 0;
+    for c in line.chars() {
+        // continuation lines start with a space which counts as well
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            output.push_str("\r\n ");
+            octets = 1;
+        }
+        output.push(c);
+        octets += c.len_utf8();
+    }
+    output.push_str("\r\n");
+}
+
+fn write_calendar(name: &str, events: &[Event], dtstamp: DateTime<Utc>) -> String {
+    let mut output = String::new();
+    let dtstamp = dtstamp.format("%Y%m%dT%H%M%SZ").to_string();
+
+    write_line(&mut output, "BEGIN:VCALENDAR");
+    write_line(&mut output, "VERSION:2.0");
+    write_line(&mut output, &format!("PRODID:{}", PRODUCT_ID));
+    write_line(&mut output, "CALSCALE:GREGORIAN");
+    write_line(&mut output, "METHOD:PUBLISH");
+    write_line(&mut output, &format!("X-WR-CALNAME:{}", escape_text(name)));
+
+    for event in events {
+        let end = event.date.checked_add_days(Days::new(1)).unwrap();
+        write_line(&mut output, "BEGIN:VEVENT");
+        write_line(&mut output, &format!("UID:{}", event.uid));
+        write_line(&mut output, &format!("DTSTAMP:{}", dtstamp));
+        write_line(
+            &mut output,
+            &format!("DTSTART;VALUE=DATE:{}", event.date.format("%Y%m%d")),
+        );
+        write_line(
+            &mut output,
+            &format!("DTEND;VALUE=DATE:{}", end.format("%Y%m%d")),
+        );
+        write_line(
+            &mut output,
+            &format!("SUMMARY:{}", escape_text(&event.summary)),
+        );
+        if let Some(description) = &event.description {
+            write_line(
+                &mut output,
+                &format!("DESCRIPTION:{}", escape_text(description)),
+            );
+        }
+        if event.transparent {
+            write_line(&mut output, "TRANSP:TRANSPARENT");
+        }
+        write_line(&mut output, "END:VEVENT");
+    }
+
+    write_line(&mut output, "END:VCALENDAR");
+
+    output
+}
+
+/// Summary of a day in the lunar dates feed, e.g. "15/7 Âm lịch" or "1/4 nhuận Âm lịch"
+pub fn lunar_date_summary(date: &VNDate) -> String {
+    let leap = if date.is_leap() { " nhuận" } else { "" };
+    format!("{}/{}{} Âm lịch", date.day(), date.month(), leap)
+}
+
+fn holidays_events(from_year: i32, to_year: i32) -> Vec<Event> {
+    let mut events = vec![];
+    for year in from_year..=to_year {
+        for holiday in get_holidays(year) {
+            let date = holiday.date.get_solar_datetime().date_naive();
+            events.push(Event {
+                uid: format!("{}-{}@vncalendar", date.format("%Y%m%d"), holiday.id),
+                date,
+                summary: holiday.name_vi.to_string(),
+                description: Some(format!(
+                    "{} ({})",
+                    holiday.name_en,
+                    lunar_date_summary(&holiday.date)
+                )),
+                transparent: !holiday.is_day_off,
+            });
+        }
+    }
+
+    events
+}
+
+fn lunar_dates_events(from_year: i32, to_year: i32) -> Vec<Event> {
+    let mut events = vec![];
+    for year in from_year..=to_year {
+        for month in MONTHS {
+            for vndate in get_month_dates(year, month) {
+                let date = vndate.get_solar_datetime().date_naive();
+                events.push(Event {
+                    uid: format!("{}-lunar@vncalendar", date.format("%Y%m%d")),
+                    date,
+                    summary: lunar_date_summary(&vndate),
+                    description: None,
+                    transparent: true,
+                });
+            }
+        }
+    }
+
+    events
+}
+
+/// iCalendar (RFC 5545) feed of all holidays in the given solar years (inclusive)
+pub fn holidays_calendar(from_year: i32, to_year: i32) -> String {
+    write_calendar(
+        "Ngày lễ Việt Nam",
+        &holidays_events(from_year, to_year),
+        Utc::now(),
+    )
+}
+
+/// iCalendar (RFC 5545) feed with one all-day event per day of the given solar years
+/// (inclusive) with the lunar date as summary
+pub fn lunar_dates_calendar(from_year: i32, to_year: i32) -> String {
+    write_calendar(
+        "Âm lịch",
+        &lunar_dates_events(from_year, to_year),
+        Utc::now(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn escape_text_test() {
+        assert_eq!("a\\, b\\; c\\\\ d\\ne", escape_text("a, b; c\\ d\ne"));
+    }
+
+    #[test]
+    fn write_line_test() {
+        let mut output = String::new();
+        write_line(&mut output, "SUMMARY:short");
+        assert_eq!("SUMMARY:short\r\n", output);
+
+        let mut output = String::new();
+        let line = format!("SUMMARY:{}", "Ngày lễ ".repeat(12));
+        write_line(&mut output, &line);
+        for (i, folded) in output.split("\r\n").filter(|l| !l.is_empty()).enumerate() {
+            assert!(folded.len() <= MAX_LINE_OCTETS);
+            assert_eq!(i > 0, folded.starts_with(' '));
+        }
+        assert_eq!(
+            Some(line.as_str()),
+            output.replace("\r\n ", "").strip_suffix("\r\n")
+        );
+    }
+
+    #[test]
+    fn holidays_calendar_test() {
+        let dtstamp = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let output = write_calendar("Test", &holidays_events(2025, 2025), dtstamp);
+
+        assert!(output.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(output.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(14, output.matches("BEGIN:VEVENT").count());
+        assert!(output.contains(
+            "UID:20250129-tet_1@vncalendar\r\nDTSTAMP:20250101T000000Z\r\n\
+             DTSTART;VALUE=DATE:20250129\r\nDTEND;VALUE=DATE:20250130\r\n\
+             SUMMARY:Mùng 1 Tết Nguyên Đán\r\n"
+        ));
+    }
+
+    #[test]
+    fn lunar_dates_calendar_test() {
+        let dtstamp = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let output = write_calendar("Test", &lunar_dates_events(2024, 2025), dtstamp);
+
+        assert_eq!(366 + 365, output.matches("BEGIN:VEVENT").count());
+        assert!(output.contains(
+            "DTSTART;VALUE=DATE:20240210\r\nDTEND;VALUE=DATE:20240211\r\n\
+             SUMMARY:1/1 Âm lịch\r\n"
+        ));
+        assert!(output.contains(
+            "DTSTART;VALUE=DATE:20250818\r\nDTEND;VALUE=DATE:20250819\r\n\
+             SUMMARY:25/6 nhuận Âm lịch\r\n"
+        ));
+    }
+}
@@ -3,6 +3,14 @@ use serde::Deserialize;
 use std::collections::HashMap;
 use std::fmt::{self, Debug};
 use time::VNDate;
+pub mod age;
+pub mod almanac;
+pub mod anniversaries;
+pub mod format;
+pub mod holidays;
+pub mod ical;
+pub mod pillars;
+pub mod prose;
 pub mod time;
 
 pub const TIME_ZONE_OFFSET: i64 = 7;
@@ -100,6 +108,67 @@ pub fn get_year_month_dates(year: i32) -> HashMap<Month, Vec<VNDate>> {
     return result;
 }
 
+#[derive(Hash, Eq, PartialEq, Copy, Clone, Debug)]
+pub struct LunarMonth {
+    pub month: u32,
+    pub is_leap: bool,
+}
+
+impl fmt::Display for LunarMonth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let print_leap = if self.is_leap { "L" } else { "" };
+        return write!(f, "{}{}", self.month, print_leap);
+    }
+}
+
+fn get_dates_from(start: amlich::SolarDate, days: u32) -> Vec<VNDate> {
+    // amlich dates are in the Julian calendar before 1582, chrono is proleptic Gregorian
+    let start: DateTime<Utc> = amlich::JulianDay::from_solar_date(start)
+        .to_naive_date()
+        .and_then(|date| date.and_hms_opt(12, 0, 0))
+        .unwrap()
+        .and_utc();
+
+    (0..days as u64)
+        .map(|i| {
+            VNDate::new(
+                start.checked_add_days(Days::new(i)).unwrap(),
+                TIME_ZONE_OFFSET,
+            )
+        })
+        .collect()
+}
+
+pub fn get_lunar_month_dates(
+    lunar_year: i32,
+    lunar_month: u32,
+    is_leap: bool,
+) -> Result<Vec<VNDate>, amlich::LunarDateError> {
+    let days = amlich::days_in_lunar_month(lunar_year, lunar_month, is_leap, TIME_ZONE_OFFSET)?;
+    let start = amlich::try_lunar2solar(
+        amlich::LunarDate::new(lunar_year, lunar_month, 1, is_leap),
+        TIME_ZONE_OFFSET,
+    )?;
+
+    Ok(get_dates_from(start, days))
+}
+
+/// All dates of the lunar year by lunar month, a leap month is its own entry
+pub fn get_lunar_year_dates(
+    lunar_year: i32,
+) -> Result<HashMap<LunarMonth, Vec<VNDate>>, amlich::LunarDateError> {
+    let mut result: HashMap<LunarMonth, Vec<VNDate>> = HashMap::new();
+    for info in amlich::lunar_year_info(lunar_year, TIME_ZONE_OFFSET)?.months {
+        let month = LunarMonth {
+            month: info.month,
+            is_leap: info.is_leap,
+        };
+        result.insert(month, get_dates_from(info.start, info.days));
+    }
+
+    Ok(result)
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -153,4 +222,53 @@ mod tests {
         assert_eq!(30, res.get(&Month::November).unwrap().len());
         assert_eq!(31, res.get(&Month::December).unwrap().len());
     }
+
+    #[test]
+    fn get_lunar_month_dates_test() {
+        let res = get_lunar_month_dates(2024, 1, false).unwrap();
+        assert_eq!(29, res.len());
+        assert_eq!(10, res[0].solar_day());
+        assert_eq!(2, res[0].solar_month());
+        for (i, d) in res.iter().enumerate() {
+            assert_eq!(1, d.month());
+            assert_eq!(i as u32 + 1, d.day());
+        }
+
+        let res = get_lunar_month_dates(2025, 6, true).unwrap();
+        assert_eq!(29, res.len());
+        assert!(res.iter().all(|d| d.is_leap() && d.month() == 6));
+
+        assert_eq!(
+            Some(amlich::LunarDateError::NoSuchLeapMonth {
+                year: 2024,
+                month: 6
+            }),
+            get_lunar_month_dates(2024, 6, true).err()
+        );
+    }
+
+    #[test]
+    fn get_lunar_year_dates_test() {
+        let res = get_lunar_year_dates(2025).unwrap();
+        assert_eq!(13, res.len());
+        let leap = LunarMonth {
+            month: 6,
+            is_leap: true,
+        };
+        assert_eq!("6L", leap.to_string());
+        assert_eq!(29, res.get(&leap).unwrap().len());
+        let total: usize = res.values().map(|dates| dates.len()).sum();
+        assert_eq!(384, total);
+
+        assert_eq!(12, get_lunar_year_dates(2024).unwrap().len());
+
+        assert_eq!(
+            Some(amlich::LunarDateError::YearOutOfRange(1000)),
+            get_lunar_year_dates(1000).err()
+        );
+        assert_eq!(
+            Some(amlich::LunarDateError::YearOutOfRange(300000)),
+            get_lunar_year_dates(300000).err()
+        );
+    }
 }
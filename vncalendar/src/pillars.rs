@@ -0,0 +1,174 @@
+extern crate amlich;
+
+use std::f64::consts::PI;
+
+use amlich::{
+    canchi::{day_can_chi, hour_can_chi, month_can_chi, year_can_chi},
+    CanChi, SolarTerm,
+};
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDateTime, Timelike};
+
+use super::{almanac::solar_month_branch, TIME_ZONE_OFFSET};
+
+/// The four pillars (tứ trụ) of a birth, the eight characters of bát tự
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FourPillars {
+    pub year: CanChi,
+    pub month: CanChi,
+    pub day: CanChi,
+    pub hour: CanChi,
+    /// Local time the day and hour pillars are read from, the clock time of
+    /// the birth or its true solar time when a longitude is given
+    pub solar_time: NaiveDateTime,
+}
+
+// Equation of time in minutes (apparent minus mean solar time), NOAA approximation,
+// good to about half a minute
+fn equation_of_time(time: NaiveDateTime) -> f64 {
+    let day_of_year = time.ordinal0() as f64 + (time.hour() as f64 - 12.0) / 24.0;
+    let gamma = 2.0 * PI / 365.0 * day_of_year;
+
+    229.18
+        * (0.000075 + 0.001868 * gamma.cos()
+            - 0.032077 * gamma.sin()
+            - 0.014615 * (2.0 * gamma).cos()
+            - 0.040849 * (2.0 * gamma).sin())
+}
+
+/// True solar time at the given longitude in degrees, east positive
+pub fn true_solar_time(time: DateTime<FixedOffset>, longitude: f64) -> NaiveDateTime {
+    let mean = time.naive_utc() + Duration::milliseconds((longitude * 240_000.0).round() as i64);
+    mean + Duration::milliseconds((equation_of_time(mean) * 60_000.0).round() as i64)
+}
+
+// The solar month the instant falls in: its first term and the year the term starts in.
+// Only the terms at even positions from Lập Xuân start a month.
+fn solar_month(time: DateTime<FixedOffset>) -> (SolarTerm, i32) {
+    let offset = FixedOffset::east_opt(TIME_ZONE_OFFSET as i32 * 3600).unwrap();
+    let year = time.with_timezone(&offset).year();
+    [year - 1, year]
+        .iter()
+        .flat_map(|year| amlich::solar_terms_of_year(*year, TIME_ZONE_OFFSET))
+        .filter(|(term, start)| term.index() % 2 == 0 && *start <= time)
+        .map(|(term, start)| (term, start.year()))
+        .next_back()
+        .unwrap()
+}
+
+/// Four pillars of a birth instant. The year pillar changes at Lập Xuân and the
+/// month pillar at the first term of every solar month, to the minute, rather than
+/// at the lunar new year and new moons. The day and hour pillars are read from the
+/// clock time of the birth, or from its true solar time when the longitude of the
+/// birthplace is given; the Tý hour from 23h already belongs to the next day.
+pub fn four_pillars(birth: DateTime<FixedOffset>, longitude: Option<f64>) -> FourPillars {
+    let (term, term_year) = solar_month(birth);
+    // Tiểu Hàn starts the last month of the year before
+    let year = match term {
+        SolarTerm::TieuHan => term_year - 1,
+        _ => term_year,
+    };
+    let month_branch = solar_month_branch(term).index() as u32;
+    // month 1 is the Dần month
+    let month = (month_branch + 10) % 12 + 1;
+
+    let solar_time = match longitude {
+        Some(longitude) => true_solar_time(birth, longitude),
+        None => birth.naive_local(),
+    };
+    let date = amlich::SolarDate::new(solar_time.year(), solar_time.month(), solar_time.day());
+    let day = match solar_time.hour() {
+        23 => {
+            let next = solar_time.date() + Duration::days(1);
+            day_can_chi(amlich::SolarDate::new(
+                next.year(),
+                next.month(),
+                next.day(),
+            ))
+        }
+        _ => day_can_chi(date),
+    };
+
+    FourPillars {
+        year: year_can_chi(year),
+        month: month_can_chi(year, month),
+        day,
+        hour: hour_can_chi(date, solar_time.hour()),
+        solar_time,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn birth(s: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(s).unwrap()
+    }
+
+    fn pillars(pillars: &FourPillars) -> [String; 4] {
+        [pillars.year, pillars.month, pillars.day, pillars.hour].map(|p| p.to_string())
+    }
+
+    #[test]
+    fn four_pillars_test() {
+        let p = four_pillars(birth("2024-05-10T08:30:00+07:00"), None);
+        assert_eq!(["Giáp Thìn", "Kỷ Tỵ", "Giáp Tuất", "Mậu Thìn"], pillars(&p));
+        assert_eq!(
+            "2024-05-10 08:30:00",
+            p.solar_time.format("%Y-%m-%d %H:%M:%S").to_string()
+        );
+    }
+
+    #[test]
+    fn lap_xuan_test() {
+        // Lập Xuân 2024 at 15:27 in Vietnam, five days before Tết
+        let p = four_pillars(birth("2024-02-04T15:00:00+07:00"), None);
+        assert_eq!(["Quý Mão", "Ất Sửu", "Mậu Tuất", "Canh Thân"], pillars(&p));
+        let p = four_pillars(birth("2024-02-04T16:00:00+07:00"), None);
+        assert_eq!(
+            ["Giáp Thìn", "Bính Dần", "Mậu Tuất", "Canh Thân"],
+            pillars(&p)
+        );
+        // the same instant seen from another offset
+        let p = four_pillars(birth("2024-02-04T10:00:00+01:00"), None);
+        assert_eq!(
+            ["Giáp Thìn", "Bính Dần"],
+            [p.year, p.month].map(|p| p.to_string())
+        );
+
+        // after Tết Quý Mão on 22/1/2023 but before Lập Xuân, still a Nhâm Dần year
+        let p = four_pillars(birth("2023-01-25T12:00:00+07:00"), None);
+        assert_eq!(
+            ["Nhâm Dần", "Quý Sửu"],
+            [p.year, p.month].map(|p| p.to_string())
+        );
+        // Đại Tuyết starts the Tý month in December
+        let p = four_pillars(birth("2023-12-20T12:00:00+07:00"), None);
+        assert_eq!(
+            ["Quý Mão", "Giáp Tý"],
+            [p.year, p.month].map(|p| p.to_string())
+        );
+    }
+
+    #[test]
+    fn late_ty_hour_test() {
+        let p = four_pillars(birth("2024-05-10T23:30:00+07:00"), None);
+        assert_eq!(["Giáp Thìn", "Kỷ Tỵ", "Ất Hợi", "Bính Tý"], pillars(&p));
+    }
+
+    #[test]
+    fn true_solar_time_test() {
+        // Hà Nội is 3 minutes ahead of UTC+7 in mean time, the sun runs 14 minutes
+        // late in early February
+        let t = true_solar_time(birth("2024-02-10T13:05:00+07:00"), 105.85);
+        assert_eq!("12:54", t.format("%H:%M").to_string());
+        // and 16 minutes early in early November
+        let t = true_solar_time(birth("2024-11-03T12:00:00+07:00"), 105.0);
+        assert_eq!("12:16", t.format("%H:%M").to_string());
+
+        let p = four_pillars(birth("2024-02-10T13:05:00+07:00"), None);
+        assert_eq!("Tân Mùi", p.hour.to_string());
+        let p = four_pillars(birth("2024-02-10T13:05:00+07:00"), Some(105.85));
+        assert_eq!("Canh Ngọ", p.hour.to_string());
+    }
+}
@@ -0,0 +1,106 @@
+use chrono::Datelike;
+
+use super::{
+    format::{lunar_month_name, vietnamese_weekday},
+    time::VNDate,
+};
+
+/// Lunar day as spoken: mùng 1 to mùng 10, rằm for the 15th, the number otherwise
+pub fn lunar_day_text(day: u32) -> String {
+    match day {
+        1..=10 => format!("mùng {day}"),
+        15 => "rằm".to_string(),
+        _ => day.to_string(),
+    }
+}
+
+/// Lunar month by its name as `%N` formats it, e.g. Giêng, Tư or Chạp,
+/// followed by nhuận for a leap month
+pub fn lunar_month_text(month: u32, is_leap: bool) -> String {
+    let name = lunar_month_name(month);
+    match is_leap {
+        true => format!("{name} nhuận"),
+        false => name.to_string(),
+    }
+}
+
+/// The date as written on printed calendars, e.g.
+/// `Thứ Sáu, ngày 10 tháng 5 năm 2024, tức ngày mùng 3 tháng Tư năm Giáp Thìn`,
+/// with the Hán characters of the year after its Can Chi if asked
+pub fn vietnamese_text(date: &VNDate, with_han_nom: bool) -> String {
+    let weekday = vietnamese_weekday(date.get_solar_datetime().weekday());
+    let year_can_chi = date.year_can_chi();
+    let han_nom = match with_han_nom {
+        true => format!(" ({})", year_can_chi.han()),
+        false => String::new(),
+    };
+
+    format!(
+        "{weekday}, ngày {} tháng {} năm {}, tức ngày {} tháng {} năm {year_can_chi}{han_nom}",
+        date.solar_day(),
+        date.solar_month(),
+        date.solar_year(),
+        lunar_day_text(date.day()),
+        lunar_month_text(date.month(), date.is_leap()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vietnamese_text_test() {
+        let d = VNDate::from_solar_date(2024, 5, 10).unwrap();
+        assert_eq!(
+            "Thứ Sáu, ngày 10 tháng 5 năm 2024, tức ngày mùng 3 tháng Tư năm Giáp Thìn",
+            vietnamese_text(&d, false)
+        );
+        assert_eq!(
+            "Thứ Sáu, ngày 10 tháng 5 năm 2024, tức ngày mùng 3 tháng Tư năm Giáp Thìn (甲辰)",
+            vietnamese_text(&d, true)
+        );
+
+        let d = VNDate::from_solar_date(2024, 2, 24).unwrap();
+        assert_eq!(
+            "Thứ Bảy, ngày 24 tháng 2 năm 2024, tức ngày rằm tháng Giêng năm Giáp Thìn",
+            vietnamese_text(&d, false)
+        );
+
+        let d = VNDate::from_solar_date(2025, 1, 28).unwrap();
+        assert_eq!(
+            "Thứ Ba, ngày 28 tháng 1 năm 2025, tức ngày 29 tháng Chạp năm Giáp Thìn",
+            vietnamese_text(&d, false)
+        );
+
+        let d = VNDate::from_solar_date(2025, 7, 25).unwrap();
+        assert_eq!(
+            "Thứ Sáu, ngày 25 tháng 7 năm 2025, tức ngày mùng 1 tháng Sáu nhuận năm Ất Tỵ",
+            vietnamese_text(&d, false)
+        );
+    }
+
+    #[test]
+    fn lunar_month_text_test() {
+        assert_eq!("Giêng", lunar_month_text(1, false));
+        assert_eq!("Một", lunar_month_text(11, false));
+        assert_eq!("Sáu nhuận", lunar_month_text(6, true));
+
+        // the same name as the formatter
+        let d = VNDate::from_solar_date(2023, 12, 20).unwrap();
+        assert_eq!(11, d.month());
+        assert_eq!(
+            format!("tháng {}", lunar_month_text(d.month(), d.is_leap())),
+            d.format(Some("tháng %N %l")).unwrap().trim_end()
+        );
+    }
+
+    #[test]
+    fn lunar_day_text_test() {
+        assert_eq!("mùng 1", lunar_day_text(1));
+        assert_eq!("mùng 10", lunar_day_text(10));
+        assert_eq!("11", lunar_day_text(11));
+        assert_eq!("rằm", lunar_day_text(15));
+        assert_eq!("30", lunar_day_text(30));
+    }
+}
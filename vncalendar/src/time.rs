@@ -1,32 +1,130 @@
 extern crate amlich;
-use std::fmt::{self};
-
-use chrono::{DateTime, Datelike, Days, Duration, FixedOffset, Months, TimeDelta, Utc};
-use serde::{ser::SerializeStruct, Serialize, Serializer};
-
-use super::TIME_ZONE_OFFSET;
-
-#[derive(Clone)]
+use std::{
+    cmp::Ordering,
+    fmt::{self},
+    hash::{Hash, Hasher},
+};
+
+use amlich::{CanChi, TimeZoneRule};
+use chrono::NaiveDate;
+use chrono::{
+    DateTime, Datelike, Days, Duration, FixedOffset, Months, TimeDelta, TimeZone, Timelike, Utc,
+};
+use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{
+    age::{age, con_giap, Age},
+    almanac::{almanac, mansion, truc, Almanac, Mansion, Truc},
+    format::{format_date, FormatError},
+    pillars::{four_pillars, FourPillars},
+    prose::vietnamese_text,
+    TIME_ZONE_OFFSET,
+};
+
+#[derive(Clone, Debug)]
 pub struct VNDate {
     solar_time: DateTime<FixedOffset>,
     lunar_date: amlich::LunarDate,
-    time_zone_offset: i64,
+    time_zone: amlich::TimeZone,
+    calendar: amlich::CalendarSystem,
+}
+
+// UTC+7, Vietnam/North, Vietnam/South or Korea
+fn time_zone_name(time_zone: amlich::TimeZone) -> String {
+    match time_zone {
+        amlich::TimeZone::Fixed(hours) => format!("UTC{hours:+}"),
+        amlich::TimeZone::Vietnam(amlich::VietnamRegion::North) => "Vietnam/North".to_string(),
+        amlich::TimeZone::Vietnam(amlich::VietnamRegion::South) => "Vietnam/South".to_string(),
+        amlich::TimeZone::Korea => "Korea".to_string(),
+    }
 }
 
+fn parse_time_zone(name: &str) -> Option<amlich::TimeZone> {
+    match name {
+        "Vietnam/North" => Some(amlich::VietnamRegion::North.into()),
+        "Vietnam/South" => Some(amlich::VietnamRegion::South.into()),
+        "Korea" => Some(amlich::TimeZone::Korea),
+        _ => name
+            .strip_prefix("UTC")?
+            .parse()
+            .ok()
+            .map(amlich::TimeZone::Fixed),
+    }
+}
+
+/// The solar time in RFC 3339, time zone and calendar are written along the dates
+/// so that the value reads back the same
 impl Serialize for VNDate {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("VNDate", 3)?;
+        let mut state = serializer.serialize_struct("VNDate", 6)?;
         state.serialize_field("lunar", &format!("{}", self.lunar_date))?;
         state.serialize_field("solar", &format!("{}", self.solar_time.date_naive()))?;
         state.serialize_field("is_leap", &self.lunar_date.is_leap)?;
+        state.serialize_field("time", &self.solar_time.to_rfc3339())?;
+        state.serialize_field("time_zone", &time_zone_name(self.time_zone))?;
+        state.serialize_field("calendar", self.calendar.name())?;
         state.end()
     }
 }
 
-const STANDARD_ERROR: &str = "Invalid date format, should be similar as yyyy-mm-dd or %y-%m-%d";
+#[derive(Deserialize)]
+struct VNDateFields {
+    solar: String,
+    time: Option<String>,
+    time_zone: Option<String>,
+    calendar: Option<String>,
+}
+
+/// Reads back the serialized form. Without `time` the date is midday of `solar`,
+/// without `time_zone` it is in the time zone of `calendar`, Vietnamese by default.
+impl<'de> Deserialize<'de> for VNDate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let fields = VNDateFields::deserialize(deserializer)?;
+        let calendar = match &fields.calendar {
+            None => amlich::CalendarSystem::default(),
+            Some(name) => amlich::CALENDAR_SYSTEMS
+                .into_iter()
+                .find(|calendar| calendar.name() == name)
+                .ok_or_else(|| {
+                    de::Error::unknown_variant(name, &["Vietnamese", "Chinese", "Korean"])
+                })?,
+        };
+        let time_zone = match &fields.time_zone {
+            None => calendar.time_zone(),
+            Some(name) => parse_time_zone(name).ok_or_else(|| {
+                de::Error::invalid_value(de::Unexpected::Str(name), &"a time zone as UTC+7")
+            })?,
+        };
+        let date = match &fields.time {
+            Some(time) => DateTime::parse_from_rfc3339(time)
+                .map(|time| VNDate::new_with_time_zone(time.to_utc(), time_zone))
+                .map_err(|_| {
+                    de::Error::invalid_value(de::Unexpected::Str(time), &"an RFC 3339 time")
+                })?,
+            None => NaiveDate::parse_from_str(&fields.solar, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| {
+                    VNDate::from_solar_date_with_time_zone(
+                        date.year(),
+                        date.month(),
+                        date.day(),
+                        time_zone,
+                    )
+                })
+                .ok_or_else(|| {
+                    de::Error::invalid_value(de::Unexpected::Str(&fields.solar), &"yyyy-mm-dd")
+                })?,
+        };
+
+        Ok(VNDate { calendar, ..date })
+    }
+}
 
 const VIETNAMESE_TIME_ZONE_OFFSET: i32 = 7 * 60 * 60;
 
@@ -35,45 +133,146 @@ fn get_vietnamese_tz() -> FixedOffset {
     FixedOffset::east_opt(VIETNAMESE_TIME_ZONE_OFFSET).unwrap()
 }
 
+// Time zone of the solar time: the offset itself for a fixed offset, the offset in
+// force at that date for a historical rule. None if chrono has no such offset, a day
+// or more
+fn get_local_tz(solar_time: DateTime<Utc>, time_zone: amlich::TimeZone) -> Option<FixedOffset> {
+    let jd = amlich::JulianDay::from(solar_time.date_naive()).value();
+    let seconds = time_zone.offset_minutes(jd).checked_mul(60)?;
+    FixedOffset::east_opt(i32::try_from(seconds).ok()?)
+}
+
+// chrono dates are proleptic Gregorian while amlich uses the Julian calendar before 1582
+#[inline]
+fn amlich_solar_date(date: NaiveDate) -> amlich::SolarDate {
+    amlich::JulianDay::from(date).to_solar_date()
+}
+
 impl VNDate {
+    /// The solar_time must be in UTC+7, the lunar date is the one at time_zone_offset
     pub fn new_by_vietnamese_tz(solar_time: DateTime<FixedOffset>, time_zone_offset: i64) -> Self {
         if solar_time.timezone() != get_vietnamese_tz() {
             panic!("the solar_time must have vietnamese timezone")
         }
 
-        let lunar_date = amlich::solar2lunar(
-            amlich::SolarDate::new(solar_time.year(), solar_time.month(), solar_time.day()),
-            time_zone_offset,
-        );
+        Self::from_local_time(solar_time, amlich::TimeZone::Fixed(time_zone_offset))
+    }
+
+    /// The solar_time must have the offset the time zone has at that date
+    pub fn new_by_time_zone(
+        solar_time: DateTime<FixedOffset>,
+        time_zone: amlich::TimeZone,
+    ) -> Self {
+        if Some(solar_time.timezone()) != get_local_tz(solar_time.to_utc(), time_zone) {
+            panic!("the solar_time must have the offset of the time zone at that date")
+        }
+
+        Self::from_local_time(solar_time, time_zone)
+    }
+
+    fn from_local_time(solar_time: DateTime<FixedOffset>, time_zone: amlich::TimeZone) -> Self {
+        let lunar_date = amlich::solar2lunar(amlich_solar_date(solar_time.date_naive()), time_zone);
 
         Self {
-            solar_time: solar_time,
-            time_zone_offset: time_zone_offset,
-            lunar_date: lunar_date,
+            solar_time,
+            time_zone,
+            calendar: amlich::CalendarSystem::Vietnamese,
+            lunar_date,
         }
     }
 
     pub fn new(solar_time: DateTime<Utc>, time_zone_offset: i64) -> Self {
-        let vn_solar_time = solar_time.with_timezone(&get_vietnamese_tz());
-        Self::new_by_vietnamese_tz(vn_solar_time, time_zone_offset)
+        Self::new_with_time_zone(solar_time, amlich::TimeZone::Fixed(time_zone_offset))
+    }
+
+    /// Converts with a historical rule, e.g. `VietnamRegion::South` for dates in
+    /// Sài Gòn before 1975. The solar time stays in UTC+7 for an offset chrono can't
+    /// represent
+    pub fn new_with_time_zone(solar_time: DateTime<Utc>, time_zone: amlich::TimeZone) -> Self {
+        let local_tz = get_local_tz(solar_time, time_zone).unwrap_or_else(get_vietnamese_tz);
+        Self::from_local_time(solar_time.with_timezone(&local_tz), time_zone)
+    }
+
+    /// The date in another lunisolar calendar, e.g. the Chinese date for overseas families
+    pub fn new_with_calendar(solar_time: DateTime<Utc>, calendar: amlich::CalendarSystem) -> Self {
+        Self {
+            calendar,
+            ..Self::new_with_time_zone(solar_time, calendar.time_zone())
+        }
+    }
+
+    /// Same solar time converted with the given calendar
+    pub fn with_calendar(&self, calendar: amlich::CalendarSystem) -> VNDate {
+        VNDate::new_with_calendar(self.solar_time.to_utc(), calendar)
     }
 
     pub fn today() -> VNDate {
         VNDate::new(Utc::now(), TIME_ZONE_OFFSET)
     }
 
+    /// Midday of the given solar date, None if the date does not exist
+    pub fn from_solar_date(year: i32, month: u32, day: u32) -> Option<VNDate> {
+        VNDate::from_solar_date_with_time_zone(year, month, day, TIME_ZONE_OFFSET.into())
+    }
+
+    pub fn from_solar_date_with_time_zone(
+        year: i32,
+        month: u32,
+        day: u32,
+        time_zone: amlich::TimeZone,
+    ) -> Option<VNDate> {
+        let solar_time = Utc.with_ymd_and_hms(year, month, day, 12, 0, 0).single()?;
+        Some(VNDate::new_with_time_zone(solar_time, time_zone))
+    }
+
+    pub fn from_lunar_date(
+        lunar_date: amlich::LunarDate,
+    ) -> Result<VNDate, amlich::LunarDateError> {
+        VNDate::from_lunar_date_with_time_zone(lunar_date, TIME_ZONE_OFFSET.into())
+    }
+
+    pub fn from_lunar_date_with_time_zone(
+        lunar_date: amlich::LunarDate,
+        time_zone: amlich::TimeZone,
+    ) -> Result<VNDate, amlich::LunarDateError> {
+        let solar_date = amlich::JulianDay::from_lunar_date(lunar_date, time_zone)?
+            .to_naive_date()
+            .unwrap();
+        Ok(VNDate::from_solar_date_with_time_zone(
+            solar_date.year(),
+            solar_date.month(),
+            solar_date.day(),
+            time_zone,
+        )
+        .unwrap())
+    }
+
     #[inline]
     pub const fn get_lunar_date(&self) -> amlich::LunarDate {
         self.lunar_date
     }
 
+    #[inline]
+    pub const fn get_time_zone(&self) -> amlich::TimeZone {
+        self.time_zone
+    }
+
+    #[inline]
+    pub const fn get_calendar(&self) -> amlich::CalendarSystem {
+        self.calendar
+    }
+
     #[inline]
     pub const fn get_solar_datetime(&self) -> DateTime<FixedOffset> {
         self.solar_time
     }
 
+    // Offsets of a historical rule change over time, so the result may have another one
     fn with_solar_time(&self, solar_time: DateTime<FixedOffset>) -> VNDate {
-        VNDate::new_by_vietnamese_tz(solar_time, self.time_zone_offset)
+        Self {
+            calendar: self.calendar,
+            ..VNDate::new_with_time_zone(solar_time.to_utc(), self.time_zone)
+        }
     }
 
     pub fn checked_add_signed(&self, rhs: TimeDelta) -> Option<VNDate> {
@@ -116,13 +315,13 @@ impl VNDate {
         let years_in_months = years * 12;
         let d = self.solar_time + Months::new(months + years_in_months) + Days::new(days);
 
-        VNDate::new_by_vietnamese_tz(d, self.time_zone_offset)
+        self.with_solar_time(d)
     }
 
     pub fn add(&self, duration: Duration) -> VNDate {
         let d = self.solar_time + duration;
 
-        VNDate::new_by_vietnamese_tz(d, self.time_zone_offset)
+        self.with_solar_time(d)
     }
 
     pub fn equal(&self, other: &VNDate) -> bool {
@@ -164,69 +363,95 @@ impl VNDate {
         self.lunar_date.is_leap
     }
 
-    pub fn format(&self, fmt: Option<&str>) -> Result<String, &str> {
-        let s = fmt.unwrap_or("");
-
-        if s != "" {
-            let mut parts: Vec<&str> = s.split('-').collect();
-            let mut separator: &str = "-";
-            if parts.len() != 3 {
-                parts = s.split('/').collect();
-                if parts.len() != 3 {
-                    return Err(STANDARD_ERROR);
-                }
-                separator = "/";
-            }
-
-            let first = parts[0];
-            let second = parts[1];
-            let third = parts[2];
-
-            let correct_syntax: bool;
-            let mut reverse = false;
-
-            match first {
-                "yyyy" => correct_syntax = second == "mm" && third == "dd",
-                "%y" => correct_syntax = second == "%m" && third == "%d",
-                "dd" => {
-                    correct_syntax = second == "mm" && third == "yyyy";
-                    reverse = true;
-                }
-                "%d" => {
-                    correct_syntax = second == "%m" && third == "%y";
-                    reverse = true;
-                }
-                _ => correct_syntax = false,
-            };
-
-            if !correct_syntax {
-                return Err(STANDARD_ERROR);
-            }
-
-            return match reverse {
-                true => Ok(format!(
-                    "{1:02}{0}{2:02}{0}{3}",
-                    separator,
-                    self.day(),
-                    self.month(),
-                    self.year()
-                )),
-                false => Ok(format!(
-                    "{1}{0}{2:02}{0}{3:02}",
-                    separator,
-                    self.year(),
-                    self.month(),
-                    self.day()
-                )),
-            };
+    #[inline]
+    fn get_amlich_solar_date(&self) -> amlich::SolarDate {
+        amlich_solar_date(self.solar_time.date_naive())
+    }
+
+    #[inline]
+    pub fn year_can_chi(&self) -> CanChi {
+        self.lunar_date.year_can_chi()
+    }
+
+    #[inline]
+    pub fn month_can_chi(&self) -> CanChi {
+        self.lunar_date.month_can_chi()
+    }
+
+    #[inline]
+    pub fn day_can_chi(&self) -> CanChi {
+        amlich::canchi::day_can_chi(self.get_amlich_solar_date())
+    }
+
+    #[inline]
+    pub fn hour_can_chi(&self) -> CanChi {
+        amlich::canchi::hour_can_chi(self.get_amlich_solar_date(), self.solar_time.hour())
+    }
+
+    /// Zodiac animal of the lunar year, named after the calendar of the date
+    #[inline]
+    pub fn zodiac_animal(&self) -> &'static str {
+        self.calendar.zodiac_animal(self.year_can_chi().branch)
+    }
+
+    /// Con giáp of the lunar year in Vietnamese, e.g. Mèo for a Mão year
+    #[inline]
+    pub fn con_giap(&self) -> &'static str {
+        con_giap(self.year_can_chi().branch)
+    }
+
+    /// Solar term the day falls in, in the time zone of the date
+    #[inline]
+    pub fn solar_term(&self) -> amlich::SolarTerm {
+        amlich::solar_term_of_day(self.get_amlich_solar_date(), self.time_zone)
+    }
+
+    #[inline]
+    pub fn julian_day(&self) -> amlich::JulianDay {
+        amlich::JulianDay::from(self.solar_time.date_naive())
+    }
+
+    /// One of the 12 Trực, counted in the solar month of the date
+    #[inline]
+    pub fn truc(&self) -> Truc {
+        truc(self.solar_term(), self.day_can_chi().branch)
+    }
+
+    /// One of the 28 mansions (Tú), cycling by Julian day
+    #[inline]
+    pub fn mansion(&self) -> Mansion {
+        mansion(self.julian_day())
+    }
+
+    /// Format with the specifiers listed in `format::SPECIFIERS`, `%y-%m-%d` by default
+    /// or for an empty format. `yyyy-mm-dd` and `dd-mm-yyyy`, with `-` or `/`, are
+    /// accepted as before, any other format without a specifier is an error.
+    pub fn format(&self, fmt: Option<&str>) -> Result<String, FormatError> {
+        match fmt {
+            None | Some("") => format_date(self, "%y-%m-%d"),
+            Some(fmt) => format_date(self, fmt),
         }
+    }
+
+    /// Day god, lucky hours, Trực and mansion, see `almanac::almanac`
+    pub fn almanac(&self) -> Almanac {
+        almanac(self)
+    }
+
+    /// Four pillars of the solar time taken as a birth instant, see `pillars::four_pillars`
+    pub fn four_pillars(&self, longitude: Option<f64>) -> FourPillars {
+        four_pillars(self.solar_time, longitude)
+    }
+
+    /// Lunar age and yearly checks at the given date of someone born on this one,
+    /// see `age::age`
+    pub fn age_at(&self, on: &VNDate) -> Option<Age> {
+        age(self, on)
+    }
 
-        Ok(format!(
-            "{}-{:02}-{:02}",
-            self.year(),
-            self.month(),
-            self.day()
-        ))
+    /// Vietnamese prose as on printed calendars, see `prose::vietnamese_text`
+    pub fn to_vietnamese_text(&self, with_han_nom: bool) -> String {
+        vietnamese_text(self, with_han_nom)
     }
 }
 
@@ -236,6 +461,27 @@ impl PartialEq for VNDate {
     }
 }
 
+impl Eq for VNDate {}
+
+/// Ordered by the solar time, like equality
+impl Ord for VNDate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.solar_time.cmp(&other.solar_time)
+    }
+}
+
+impl PartialOrd for VNDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for VNDate {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.solar_time.hash(state);
+    }
+}
+
 impl fmt::Display for VNDate {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -347,6 +593,71 @@ mod tests {
         assert_eq!(true, d.equal(&other));
     }
 
+    #[test]
+    fn ord_test() {
+        let d1 = VNDate::from_solar_date(2024, 2, 9).unwrap();
+        let d2 = VNDate::from_solar_date(2024, 2, 10).unwrap();
+        assert!(d1 < d2);
+        assert_eq!(Some(&d2), [d2.clone(), d1.clone()].iter().max());
+
+        let days: std::collections::HashSet<VNDate> = [d1.clone(), d2, d1].into_iter().collect();
+        assert_eq!(2, days.len());
+    }
+
+    #[test]
+    fn serde_test() {
+        let d = VNDate::from_lunar_date(amlich::LunarDate::new(2006, 7, 20, true)).unwrap();
+        let json = serde_json::to_string(&d).unwrap();
+        assert_eq!(
+            r#"{"lunar":"2006-07-20L","solar":"2006-09-12","is_leap":true,"time":"2006-09-12T19:00:00+07:00","time_zone":"UTC+7","calendar":"Vietnamese"}"#,
+            json
+        );
+        let back: VNDate = serde_json::from_str(&json).unwrap();
+        assert!(back == d);
+        assert!(back.is_leap());
+
+        // only the solar date, midday in UTC+7
+        let back: VNDate = serde_json::from_str(r#"{"solar":"2006-09-12"}"#).unwrap();
+        assert_eq!("2006-07-20", back.format(None).unwrap());
+        assert!(serde_json::from_str::<VNDate>(r#"{"solar":"2006-02-30"}"#).is_err());
+        assert!(
+            serde_json::from_str::<VNDate>(r#"{"solar":"2006-02-03","time_zone":"UTC"}"#).is_err()
+        );
+    }
+
+    #[test]
+    fn serde_round_trip_test() {
+        let t = Utc.with_ymd_and_hms(2024, 2, 9, 16, 30, 0).unwrap();
+        let dates = [
+            VNDate::new(t, TIME_ZONE_OFFSET),
+            VNDate::new_with_calendar(t, amlich::CalendarSystem::Chinese),
+            VNDate::new_with_calendar(t, amlich::CalendarSystem::Korean),
+            VNDate::from_solar_date_with_time_zone(
+                1968,
+                1,
+                30,
+                amlich::VietnamRegion::South.into(),
+            )
+            .unwrap(),
+        ];
+        for d in dates {
+            let back: VNDate = serde_json::from_str(&serde_json::to_string(&d).unwrap()).unwrap();
+            assert!(back == d);
+            assert_eq!(d.solar_time.offset(), back.solar_time.offset());
+            assert_eq!(d.get_lunar_date(), back.get_lunar_date());
+            assert_eq!(d.get_time_zone(), back.get_time_zone());
+            assert_eq!(d.get_calendar(), back.get_calendar());
+        }
+
+        // converted in the time zone of the calendar
+        let d: VNDate =
+            serde_json::from_str(r#"{"solar":"1985-01-21","calendar":"Chinese"}"#).unwrap();
+        assert_eq!(amlich::TimeZone::Fixed(8), d.get_time_zone());
+        assert_eq!("1984-12-01", d.format(None).unwrap());
+        let d: VNDate = serde_json::from_str(r#"{"solar":"1985-01-21"}"#).unwrap();
+        assert_eq!("1985-01-01", d.format(None).unwrap());
+    }
+
     #[test]
     fn checked_add_signed_test() {
         // Sun, 11 Sep 2022 10:34:48 UTC
@@ -359,6 +670,36 @@ mod tests {
         assert_eq!(12, result.solar_time.day());
     }
 
+    #[test]
+    fn from_solar_date_test() {
+        let d = VNDate::from_solar_date(2024, 2, 10).unwrap();
+        assert_eq!("2024-01-01", d.format(None).unwrap());
+        assert!(VNDate::from_solar_date(2023, 2, 29).is_none());
+    }
+
+    #[test]
+    fn from_lunar_date_test() {
+        let d = VNDate::from_lunar_date(amlich::LunarDate::new(2006, 7, 20, true)).unwrap();
+        assert_eq!(2006, d.solar_year());
+        assert_eq!(9, d.solar_month());
+        assert_eq!(12, d.solar_day());
+        assert!(d.is_leap());
+        assert!(VNDate::from_lunar_date(amlich::LunarDate::new(2024, 12, 30, false)).is_err());
+    }
+
+    #[test]
+    fn can_chi_test() {
+        // Sun, 11 Sep 2022 10:34:48 UTC
+        let nanos: i64 = 1662892488_000_000_000;
+        let solar_time = DateTime::from_timestamp_nanos(nanos);
+        let d = VNDate::new(solar_time, TIME_ZONE_OFFSET);
+        assert_eq!("Nhâm Dần", d.year_can_chi().to_string());
+        assert_eq!("Kỷ Dậu", d.month_can_chi().to_string());
+        assert_eq!("Đinh Mão", d.day_can_chi().to_string());
+        // 17:34 in Vietnam
+        assert_eq!("Kỷ Dậu", d.hour_can_chi().to_string());
+    }
+
     #[test]
     fn format_test() {
         // Sun, 11 Sep 2022 10:34:48 UTC
@@ -381,7 +722,103 @@ mod tests {
             "2022/08/16".to_string(),
             d.format(Some("yyyy/mm/dd")).unwrap()
         );
+        assert_eq!(
+            "16/08/2022 (11/09/2022)".to_string(),
+            d.format(Some("%d/%m/%y (%D/%M/%Y)")).unwrap()
+        );
+        let error = d.format(Some("%y-%q-%d")).unwrap_err();
+        assert_eq!(FormatError::UnknownSpecifier("%q".to_string()), error);
+        assert_eq!("2022-08-16".to_string(), d.format(Some("")).unwrap());
         let error = d.format(Some("y-m-d")).unwrap_err();
-        assert_eq!(STANDARD_ERROR, error);
+        assert_eq!(FormatError::NoSpecifier, error);
+        assert_eq!(
+            "Invalid date format, should be similar as yyyy-mm-dd or %y-%m-%d",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn julian_calendar_test() {
+        // 9 tháng 2 1300 is 29 February in the Julian calendar, chrono is Gregorian
+        let lunar = amlich::LunarDate::new(1300, 2, 9, false);
+        let d = VNDate::from_lunar_date(lunar).unwrap();
+        assert_eq!(
+            "1300-03-08",
+            d.get_solar_datetime().date_naive().to_string()
+        );
+        assert_eq!(lunar, d.get_lunar_date());
+        assert_eq!(
+            amlich::SolarDate::new(1300, 2, 29),
+            d.julian_day().to_solar_date()
+        );
+    }
+
+    #[test]
+    fn historical_time_zone_test() {
+        // Tết Mậu Thân was a day later in the South, which used UTC+8 in 1968
+        let tet = amlich::LunarDate::new(1968, 1, 1, false);
+        let north = amlich::TimeZone::Vietnam(amlich::VietnamRegion::North);
+        let south = amlich::TimeZone::Vietnam(amlich::VietnamRegion::South);
+
+        let d = VNDate::from_lunar_date_with_time_zone(tet, north).unwrap();
+        assert_eq!(29, d.solar_day());
+        assert_eq!(7 * 3600, d.solar_time.offset().local_minus_utc());
+        let d = VNDate::from_lunar_date_with_time_zone(tet, south).unwrap();
+        assert_eq!(30, d.solar_day());
+        assert_eq!(8 * 3600, d.solar_time.offset().local_minus_utc());
+
+        let d = VNDate::from_solar_date_with_time_zone(1968, 1, 30, north).unwrap();
+        assert_eq!("1968-01-02", d.format(None).unwrap());
+        let d = VNDate::from_solar_date_with_time_zone(1968, 1, 30, south).unwrap();
+        assert_eq!("1968-01-01", d.format(None).unwrap());
+        // the offset follows the date when moving across a transition
+        let d = d.add_solar_date(10, 0, 0);
+        assert_eq!(7 * 3600, d.solar_time.offset().local_minus_utc());
+    }
+
+    #[test]
+    fn calendar_test() {
+        let d = VNDate::from_solar_date(1985, 1, 21).unwrap();
+        assert_eq!(amlich::CalendarSystem::Vietnamese, d.get_calendar());
+        assert_eq!("1985-01-01", d.format(None).unwrap());
+        assert_eq!("Buffalo", d.zodiac_animal());
+
+        let chinese = d.with_calendar(amlich::CalendarSystem::Chinese);
+        assert_eq!(21, chinese.solar_day());
+        assert_eq!("1984-12-01", chinese.format(None).unwrap());
+        assert_eq!("Rat", chinese.zodiac_animal());
+        // the calendar is kept when moving the date
+        let chinese = chinese.add(TimeDelta::days(30));
+        assert_eq!(amlich::CalendarSystem::Chinese, chinese.get_calendar());
+        assert_eq!("1985-01-01", chinese.format(None).unwrap());
+        assert_eq!("Ox", chinese.zodiac_animal());
+    }
+
+    #[test]
+    fn fixed_offset_test() {
+        // 00:30 on Tết Giáp Thìn in China is still 23:30 on the day before in Vietnam
+        let t = Utc.with_ymd_and_hms(2024, 2, 9, 16, 30, 0).unwrap();
+        let d = VNDate::new_with_calendar(t, amlich::CalendarSystem::Chinese);
+        assert_eq!(8 * 3600, d.solar_time.offset().local_minus_utc());
+        assert_eq!((10, 0), (d.solar_day(), d.solar_time.hour()));
+        assert_eq!("2024-01-01", d.format(None).unwrap());
+
+        let d = VNDate::new(t, TIME_ZONE_OFFSET);
+        assert_eq!((9, 23), (d.solar_day(), d.solar_time.hour()));
+        assert_eq!("2023-12-30", d.format(None).unwrap());
+    }
+
+    #[test]
+    fn new_by_vietnamese_tz_test() {
+        // the Chinese date of the solar day in Vietnam
+        let t = Utc.with_ymd_and_hms(1985, 1, 21, 5, 0, 0).unwrap();
+        let d = VNDate::new_by_vietnamese_tz(t.with_timezone(&get_vietnamese_tz()), 8);
+        assert_eq!(7 * 3600, d.solar_time.offset().local_minus_utc());
+        assert_eq!("1984-12-01", d.format(None).unwrap());
+
+        // no such offset in chrono, the solar time stays in UTC+7
+        let d = VNDate::new(t, 30);
+        assert_eq!(7 * 3600, d.solar_time.offset().local_minus_utc());
+        assert_eq!(21, d.solar_day());
     }
 }